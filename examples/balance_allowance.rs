@@ -1,16 +1,15 @@
 use polysqueeze::{
     client::ClobClient,
     errors::Result,
+    orders::SigType,
     types::{AssetType, BalanceAllowanceParams},
 };
 use std::env;
 
 /// Helper to fail fast if a required environment variable is missing.
 fn env_var(key: &str) -> String {
-    env::var(key).expect(&format!(
-        "{} must be set for the balance allowance example",
-        key
-    ))
+    env::var(key)
+        .unwrap_or_else(|_| panic!("{} must be set for the balance allowance example", key))
 }
 
 #[tokio::main]
@@ -28,11 +27,17 @@ async fn main() -> Result<()> {
     let mut client = ClobClient::with_l2_headers(&base_url, &private_key, chain_id, creds.clone());
 
     if let Ok(funder) = env::var("POLY_FUNDER") {
-        client.set_funder(&funder)?;
+        // 1 = proxy wallet (email login), 2 = Gnosis Safe (browser wallet)
+        let sig_type = env::var("POLY_SIGNATURE_TYPE")
+            .map(|value| value.parse::<SigType>())
+            .unwrap_or(Ok(SigType::PolyProxy))?;
+        client.set_funder(&funder, sig_type)?;
     }
 
-    let mut params = BalanceAllowanceParams::default();
-    params.asset_type = Some(AssetType::COLLATERAL);
+    let params = BalanceAllowanceParams {
+        asset_type: Some(AssetType::COLLATERAL),
+        ..Default::default()
+    };
 
     let balances = client.get_balance_allowance(Some(params)).await?;
     println!("balance allowance response: {balances:#}");
@@ -1,6 +1,7 @@
 use polysqueeze::{
     client::{ClobClient, OrderArgs},
     errors::Result,
+    orders::SigType,
     types::{GammaListParams, OrderType, Side},
 };
 use rust_decimal::Decimal;
@@ -8,7 +9,7 @@ use std::env;
 
 /// Helper to fail fast if a required environment variable is missing.
 fn env_var(key: &str) -> String {
-    env::var(key).expect(&format!("{} must be set for the order example", key))
+    env::var(key).unwrap_or_else(|_| panic!("{} must be set for the order example", key))
 }
 
 #[tokio::main]
@@ -27,7 +28,11 @@ async fn main() -> Result<()> {
     let mut client = ClobClient::with_l2_headers(&base_url, &private_key, chain_id, creds.clone());
 
     if let Ok(funder) = env::var("POLY_FUNDER") {
-        client.set_funder(&funder)?;
+        // 1 = proxy wallet (email login), 2 = Gnosis Safe (browser wallet)
+        let sig_type = env::var("POLY_SIGNATURE_TYPE")
+            .map(|value| value.parse::<SigType>())
+            .unwrap_or(Ok(SigType::PolyProxy))?;
+        client.set_funder(&funder, sig_type)?;
     }
 
     let gamma_params = GammaListParams {
@@ -42,7 +47,7 @@ async fn main() -> Result<()> {
 
     let token_id = market.clob_token_ids.first().unwrap();
 
-    let book = client.get_order_book(&token_id).await?;
+    let book = client.get_order_book(token_id).await?;
     let best_bid = book.bids.first().expect("order book has no bids").price;
     let best_ask = book.asks.first().expect("order book has no asks").price;
     let book_mid = (best_bid + best_ask) / Decimal::from(2);
@@ -50,11 +55,11 @@ async fn main() -> Result<()> {
     let order_size = min_order_size;
     let order_price = book_mid;
 
-    let args = OrderArgs::new(&token_id, order_price, order_size, Side::BUY);
+    let args = OrderArgs::new(token_id, order_price, order_size, Side::BUY);
     let signed_order = client.create_order(&args, None, None, None).await?;
 
     let response = client.post_order(signed_order, OrderType::GTC).await?;
-    println!("order posted: {}", response);
+    println!("order posted: {:?}", response);
 
     Ok(())
 }
@@ -11,7 +11,7 @@ use ratatui::backend::CrosstermBackend;
 use ratatui::layout::{Alignment, Constraint, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
 use ratatui::Frame;
 use rust_decimal::Decimal;
 use rust_decimal::prelude::ToPrimitive;
@@ -31,7 +31,7 @@ fn format_with_commas(num: Decimal) -> String {
     for (i, ch) in chars.iter().enumerate() {
         result.push(*ch);
         // Add comma every 3 digits from the right (but not at the end)
-        if (len - i - 1) % 3 == 0 && i < len - 1 {
+        if (len - i - 1).is_multiple_of(3) && i < len - 1 {
             result.push(',');
         }
     }
@@ -78,7 +78,7 @@ fn format_size_with_commas(size: Decimal) -> String {
     for (i, ch) in chars.iter().enumerate() {
         result.push(*ch);
         // Add comma every 3 digits from the right (but not at the end)
-        if (len - i - 1) % 3 == 0 && i < len - 1 {
+        if (len - i - 1).is_multiple_of(3) && i < len - 1 {
             result.push(',');
         }
     }
@@ -287,20 +287,6 @@ impl RealtimeData {
         }
     }
 
-    fn get_selected_hash(&self, asset: &str) -> Option<String> {
-        let (selected_idx, trades) = if asset == "yes" {
-            (self.yes_selected, &self.yes_data.recent_trades)
-        } else {
-            (self.no_selected, &self.no_data.recent_trades)
-        };
-
-        if let Some(idx) = selected_idx {
-            trades.get(idx).and_then(|(_, _, hash)| hash.clone())
-        } else {
-            None
-        }
-    }
-
     fn update_book(&mut self, book: &polysqueeze::wss::MarketBook) {
         if book.asset_id == self.yes_asset_id {
             self.yes_data.update_book(book);
@@ -439,7 +425,7 @@ async fn run_realtime_tui(
                 if let Ok(mut data_guard) = data.try_lock() {
                     data_guard.yes_selected = yes_selected;
                     data_guard.no_selected = no_selected;
-                    ui_realtime_sync(f, market, yes_label, no_label, &*data_guard, &mut yes_state, &mut no_state, active_side);
+                    ui_realtime_sync(f, market, (yes_label, no_label), &data_guard, &mut yes_state, &mut no_state, active_side);
                 }
             }).map_err(|e| PolyError::internal(format!("Failed to draw terminal: {}", e), e))?;
         }
@@ -447,87 +433,85 @@ async fn run_realtime_tui(
         // 非阻塞检查按键和鼠标事件
         if event::poll(std::time::Duration::from_millis(100)).map_err(|e| PolyError::internal(format!("Failed to poll event: {}", e), e))? {
             match event::read().map_err(|e| PolyError::internal(format!("Terminal I/O error: {}", e), e))? {
-                Event::Key(key) => {
-                    if key.kind == KeyEventKind::Press {
-                        match key.code {
-                            KeyCode::Char('q') | KeyCode::Esc => {
-                                break Ok(());
-                            }
-                            KeyCode::Tab | KeyCode::Left | KeyCode::Right => {
-                                // Switch between Yes and No sides
-                                // Left goes to Yes, Right goes to No
-                                let new_side = if key.code == KeyCode::Left {
-                                    "yes"
-                                } else if key.code == KeyCode::Right {
-                                    "no"
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    match key.code {
+                        KeyCode::Char('q') | KeyCode::Esc => {
+                            break Ok(());
+                        }
+                        KeyCode::Tab | KeyCode::Left | KeyCode::Right => {
+                            // Switch between Yes and No sides
+                            // Left goes to Yes, Right goes to No
+                            let new_side = if key.code == KeyCode::Left {
+                                "yes"
+                            } else if key.code == KeyCode::Right {
+                                "no"
+                            } else {
+                                // Tab toggles
+                                if active_side == "yes" { "no" } else { "yes" }
+                            };
+                            
+                            active_side = new_side;
+                            
+                            // Ensure selection index is valid for the new side
+                            if let Ok(data_guard) = data.try_lock() {
+                                let (trades_len, state) = if new_side == "yes" {
+                                    (data_guard.yes_data.recent_trades.len(), &mut yes_state)
                                 } else {
-                                    // Tab toggles
-                                    if active_side == "yes" { "no" } else { "yes" }
+                                    (data_guard.no_data.recent_trades.len(), &mut no_state)
                                 };
                                 
-                                active_side = new_side;
-                                
-                                // Ensure selection index is valid for the new side
-                                if let Ok(data_guard) = data.try_lock() {
-                                    let (trades_len, state) = if new_side == "yes" {
-                                        (data_guard.yes_data.recent_trades.len(), &mut yes_state)
-                                    } else {
-                                        (data_guard.no_data.recent_trades.len(), &mut no_state)
-                                    };
-                                    
-                                    if trades_len == 0 {
-                                        state.select(None);
-                                    } else {
-                                        let current = state.selected().unwrap_or(0);
-                                        if current >= trades_len {
-                                            state.select(Some(trades_len.saturating_sub(1)));
-                                        } else if state.selected().is_none() {
-                                            state.select(Some(0));
-                                        }
+                                if trades_len == 0 {
+                                    state.select(None);
+                                } else {
+                                    let current = state.selected().unwrap_or(0);
+                                    if current >= trades_len {
+                                        state.select(Some(trades_len.saturating_sub(1)));
+                                    } else if state.selected().is_none() {
+                                        state.select(Some(0));
                                     }
                                 }
                             }
-                            KeyCode::Char('j') | KeyCode::Down => {
-                                if let Ok(data_guard) = data.try_lock() {
-                                    let (trades_len, state) = if active_side == "yes" {
-                                        (data_guard.yes_data.recent_trades.len(), &mut yes_state)
-                                    } else {
-                                        (data_guard.no_data.recent_trades.len(), &mut no_state)
-                                    };
-                                    
-                                    if trades_len > 0 {
-                                        let i = state.selected().unwrap_or(0);
-                                        let max = trades_len.saturating_sub(1);
-                                        if i < max {
-                                            state.select(Some(i + 1));
-                                        }
+                        }
+                        KeyCode::Char('j') | KeyCode::Down => {
+                            if let Ok(data_guard) = data.try_lock() {
+                                let (trades_len, state) = if active_side == "yes" {
+                                    (data_guard.yes_data.recent_trades.len(), &mut yes_state)
+                                } else {
+                                    (data_guard.no_data.recent_trades.len(), &mut no_state)
+                                };
+                                
+                                if trades_len > 0 {
+                                    let i = state.selected().unwrap_or(0);
+                                    let max = trades_len.saturating_sub(1);
+                                    if i < max {
+                                        state.select(Some(i + 1));
                                     }
                                 }
                             }
-                            KeyCode::Char('k') | KeyCode::Up => {
-                                if let Ok(data_guard) = data.try_lock() {
-                                    let (trades_len, state) = if active_side == "yes" {
-                                        (data_guard.yes_data.recent_trades.len(), &mut yes_state)
-                                    } else {
-                                        (data_guard.no_data.recent_trades.len(), &mut no_state)
-                                    };
-                                    
-                                    if trades_len > 0 {
-                                        let i = state.selected().unwrap_or(0);
-                                        if i > 0 {
-                                            state.select(Some(i - 1));
-                                        } else if state.selected().is_none() {
-                                            // If no selection, select first item
-                                            state.select(Some(0));
-                                        }
+                        }
+                        KeyCode::Char('k') | KeyCode::Up => {
+                            if let Ok(data_guard) = data.try_lock() {
+                                let (trades_len, state) = if active_side == "yes" {
+                                    (data_guard.yes_data.recent_trades.len(), &mut yes_state)
+                                } else {
+                                    (data_guard.no_data.recent_trades.len(), &mut no_state)
+                                };
+                                
+                                if trades_len > 0 {
+                                    let i = state.selected().unwrap_or(0);
+                                    if i > 0 {
+                                        state.select(Some(i - 1));
+                                    } else if state.selected().is_none() {
+                                        // If no selection, select first item
+                                        state.select(Some(0));
                                     }
                                 }
                             }
-                            KeyCode::Enter => {
-                                // Enter key disabled - no action
-                            }
-                            _ => {}
                         }
+                        KeyCode::Enter => {
+                            // Enter key disabled - no action
+                        }
+                        _ => {}
                     }
                 }
                 Event::Mouse(_mouse_event) => {
@@ -565,13 +549,13 @@ async fn run_realtime_tui(
 fn ui_realtime_sync(
     f: &mut Frame,
     market: &Market,
-    yes_label: &str,
-    no_label: &str,
+    labels: (&str, &str),
     data: &RealtimeData,
     yes_state: &mut ListState,
     no_state: &mut ListState,
     active_side: &str,
 ) {
+    let (yes_label, no_label) = labels;
     let size = f.area();
     let chunks = Layout::default()
         .constraints([
@@ -713,15 +697,15 @@ fn render_asset_orderbook(
                 });
             } else {
                 // 沒有找到最接近的，按價格從高到低排序
-                asks_sorted.sort_by(|a, b| b.price.cmp(&a.price));
+                asks_sorted.sort_by_key(|level| std::cmp::Reverse(level.price));
             }
         } else {
             // 沒有 >= best_bid 的 ask，按價格從高到低排序
-            asks_sorted.sort_by(|a, b| b.price.cmp(&a.price));
+            asks_sorted.sort_by_key(|level| std::cmp::Reverse(level.price));
         }
     } else {
         // 沒有 best_bid，按價格從高到低排序
-        asks_sorted.sort_by(|a, b| b.price.cmp(&a.price));
+        asks_sorted.sort_by_key(|level| std::cmp::Reverse(level.price));
     }
     
     // 反轉 Asks 順序（top down 反轉）
@@ -771,7 +755,7 @@ fn render_asset_orderbook(
 
     // Bids (最下面，緊鄰底部) - 買單，價格從高到低排序（從上到下）
     let mut bids_sorted: Vec<_> = data.bids.iter().collect();
-    bids_sorted.sort_by(|a, b| b.price.cmp(&a.price)); // 降序：高價在上
+    bids_sorted.sort_by_key(|level| std::cmp::Reverse(level.price)); // 降序：高價在上
     
     // Calculate max sizes for alignment
     let max_size_width = bids_sorted.iter()
@@ -957,37 +941,6 @@ async fn handle_events(
 }
 
 
-/// Open a URL in the default browser
-fn open_url(url: &str) -> Result<()> {
-    #[cfg(target_os = "windows")]
-    {
-        std::process::Command::new("cmd")
-            .args(["/C", "start", ""])
-            .arg(url)
-            .spawn()
-            .map_err(|e| PolyError::internal(format!("Failed to open URL: {}", e), e))?;
-    }
-    #[cfg(target_os = "macos")]
-    {
-        std::process::Command::new("open")
-            .arg(url)
-            .spawn()
-            .map_err(|e| PolyError::internal(format!("Failed to open URL: {}", e), e))?;
-    }
-    #[cfg(target_os = "linux")]
-    {
-        std::process::Command::new("xdg-open")
-            .arg(url)
-            .spawn()
-            .map_err(|e| PolyError::internal(format!("Failed to open URL: {}", e), e))?;
-    }
-    #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
-    {
-        return Err(PolyError::validation("Unsupported platform for opening URLs"));
-    }
-    Ok(())
-}
-
 fn derive_asset_ids(market: &Market) -> Option<Vec<String>> {
     if !market.clob_token_ids.is_empty() {
         return Some(market.clob_token_ids.clone());
@@ -1075,77 +1028,6 @@ async fn select_market_tui(markets: &[Market]) -> Result<Market> {
     result
 }
 
-/// TUI for selecting Yes or No asset from a market
-async fn select_asset_tui(market: &Market) -> Result<String> {
-    let asset_ids = derive_asset_ids(market);
-    
-    if asset_ids.is_none() || asset_ids.as_ref().unwrap().len() < 2 {
-        return Err(PolyError::validation(
-            "Market does not have Yes/No tokens available",
-        ));
-    }
-
-    let yes_token = &market.tokens[0];
-    let no_token = &market.tokens[1];
-    let assets: Vec<(&str, &str, &str)> = vec![
-        ("Yes", yes_token.token_id.as_str(), yes_token.outcome.as_str()),
-        ("No", no_token.token_id.as_str(), no_token.outcome.as_str()),
-    ];
-
-    // Setup terminal
-    enable_raw_mode().map_err(|e| PolyError::internal(format!("Failed to enable raw mode: {}", e), e))?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture).map_err(|e| PolyError::internal(format!("Failed to setup terminal: {}", e), e))?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = ratatui::Terminal::new(backend).map_err(|e| PolyError::internal(format!("Failed to create terminal: {}", e), e))?;
-
-    let mut state = ListState::default();
-    state.select(Some(0));
-
-    let result = loop {
-        terminal.draw(|f| ui_asset_selection(f, market, &assets, &mut state)).map_err(|e| PolyError::internal(format!("Failed to draw terminal: {}", e), e))?;
-
-        if let Event::Key(key) = event::read().map_err(|e| PolyError::internal(format!("Terminal I/O error: {}", e), e))? {
-            if key.kind == KeyEventKind::Press {
-                match key.code {
-                    KeyCode::Char('q') | KeyCode::Esc => {
-                        break Err(PolyError::validation("User cancelled asset selection"));
-                    }
-                    KeyCode::Char('j') | KeyCode::Down => {
-                        let i = state.selected().unwrap_or(0);
-                        if i < assets.len().saturating_sub(1) {
-                            state.select(Some(i + 1));
-                        }
-                    }
-                    KeyCode::Char('k') | KeyCode::Up => {
-                        let i = state.selected().unwrap_or(0);
-                        if i > 0 {
-                            state.select(Some(i - 1));
-                        }
-                    }
-                    KeyCode::Enter => {
-                        if let Some(selected) = state.selected() {
-                            break Ok(assets[selected].1.to_string());
-                        }
-                    }
-                    _ => {}
-                }
-            }
-        }
-    };
-
-    // Restore terminal
-    disable_raw_mode().map_err(|e| PolyError::internal(format!("Failed to disable raw mode: {}", e), e))?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    ).map_err(|e| PolyError::internal(format!("Failed to restore terminal: {}", e), e))?;
-    terminal.show_cursor().map_err(|e| PolyError::internal(format!("Failed to show cursor: {}", e), e))?;
-
-    result
-}
-
 /// Render the market list UI
 fn ui_market_list(f: &mut Frame, markets: &[&Market], state: &mut ListState) {
     let size = f.area();
@@ -1240,95 +1122,3 @@ fn ui_market_list(f: &mut Frame, markets: &[&Market], state: &mut ListState) {
         .block(Block::default().borders(Borders::ALL));
     f.render_widget(footer, chunks[2]);
 }
-
-/// Render the asset selection UI
-fn ui_asset_selection(
-    f: &mut Frame,
-    market: &Market,
-    assets: &[(&str, &str, &str)],
-    state: &mut ListState,
-) {
-    let size = f.area();
-
-    let chunks = Layout::default()
-        .constraints([
-            Constraint::Length(5), // Market info
-            Constraint::Min(0),    // Asset list
-            Constraint::Length(3), // Footer
-        ])
-        .split(size);
-
-    // Market info header
-    let market_info = vec![
-        Line::from(vec![
-            Span::styled("Question: ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
-            Span::raw(&market.question),
-        ]),
-        Line::from(vec![
-            Span::styled("Condition ID: ", Style::default().fg(Color::Cyan)),
-            Span::raw(&market.condition_id),
-        ]),
-        Line::from(vec![
-            Span::styled("Liquidity: ", Style::default().fg(Color::Green)),
-            Span::raw(
-                market
-                    .liquidity_num
-                    .map(|l| format!("${}", format_with_commas(l)))
-                    .unwrap_or_else(|| "N/A".to_string()),
-            ),
-        ]),
-    ];
-
-    let info_block = Paragraph::new(market_info)
-        .block(Block::default().borders(Borders::ALL).title("Market Information"))
-        .wrap(Wrap { trim: true });
-    f.render_widget(info_block, chunks[0]);
-
-    // Asset list
-    let items: Vec<ListItem> = assets
-        .iter()
-        .enumerate()
-        .map(|(idx, (label, token_id, outcome))| {
-            let line = Line::from(vec![
-                Span::styled(
-                    format!("{:2}. ", idx + 1),
-                    Style::default().fg(Color::DarkGray),
-                ),
-                Span::styled(
-                    format!("{} ", label),
-                    Style::default().fg(if *label == "Yes" { Color::Green } else { Color::Red }).add_modifier(Modifier::BOLD),
-                ),
-                Span::raw(format!("({}) ", outcome)),
-                Span::styled(
-                    format!("Token: {}", &token_id[..20]),
-                    Style::default().fg(Color::DarkGray),
-                ),
-            ]);
-
-            ListItem::new(line)
-        })
-        .collect();
-
-    let list = List::new(items)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("選擇資產 (Select Asset)"),
-        )
-        .highlight_style(
-            Style::default()
-                .bg(Color::Blue)
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
-        )
-        .highlight_symbol(">> ");
-
-    f.render_stateful_widget(list, chunks[1], state);
-
-    // Footer
-    let footer = Paragraph::new("↑/↓: Navigate | Enter: Select | Q/ESC: Cancel")
-        .style(Style::default().fg(Color::DarkGray))
-        .alignment(Alignment::Center)
-        .block(Block::default().borders(Borders::ALL));
-    f.render_widget(footer, chunks[2]);
-}
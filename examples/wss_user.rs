@@ -2,7 +2,8 @@ use polysqueeze::{
     OrderArgs,
     client::ClobClient,
     errors::{PolyError, Result},
-    types::{GammaListParams, OrderType, Side},
+    orders::SigType,
+    types::{GammaListParams, OrderOutcome, OrderType, Side},
     wss::{WssUserClient, WssUserEvent},
 };
 use rust_decimal::{Decimal, prelude::FromPrimitive};
@@ -10,7 +11,7 @@ use std::{env, str::FromStr};
 
 /// Fail fast when an expected environment variable is missing.
 fn env_var(key: &str) -> String {
-    env::var(key).expect(&format!("{} must be set for the user-channel example", key))
+    env::var(key).unwrap_or_else(|_| panic!("{} must be set for the user-channel example", key))
 }
 
 #[tokio::main]
@@ -29,7 +30,11 @@ async fn main() -> Result<()> {
         ClobClient::with_l2_headers(&base_url, &private_key, chain_id, creds.clone());
 
     if let Ok(funder) = env::var("POLY_FUNDER") {
-        l2_client.set_funder(&funder)?;
+        // 1 = proxy wallet (email login), 2 = Gnosis Safe (browser wallet)
+        let sig_type = env::var("POLY_SIGNATURE_TYPE")
+            .map(|value| value.parse::<SigType>())
+            .unwrap_or(Ok(SigType::PolyProxy))?;
+        l2_client.set_funder(&funder, sig_type)?;
     }
 
     let min_liquidity = env::var("POLY_WSS_MIN_LIQUIDITY")
@@ -105,13 +110,12 @@ async fn main() -> Result<()> {
         .create_order(&order_args, None, None, None)
         .await?;
     let response = l2_client.post_order(signed_order, OrderType::GTC).await?;
-    let order_id = response
-        .get("orderID")
-        .and_then(|value| value.as_str())
-        .ok_or_else(|| PolyError::validation("Post order response missing orderID"))?
-        .to_string();
+    if let OrderOutcome::Rejected { reason } = &response.outcome {
+        return Err(PolyError::validation(format!("Order rejected: {}", reason)));
+    }
+    let order_id = response.order_id.clone();
     println!(
-        "Placed order on {} @ {}: {response:#}",
+        "Placed order on {} @ {}: {response:#?}",
         token_id, order_price
     );
     println!(
@@ -0,0 +1,403 @@
+//! Pool of trading accounts sharing one transport
+//!
+//! Every account gets its own [`ClobClient`] (signer, API key, funder), while
+//! the HTTP connection pool, market metadata cache, server clock and rate
+//! limiter are built once and shared through [`ClobClientBuilder::shared`].
+
+use crate::auth::PolySigner;
+use crate::client::{ApiCreds, ClobClient, ClobClientBuilder, DataApiClient};
+use crate::errors::{PolyError, Result};
+use crate::orders::SigType;
+use crate::types::{
+    AssetType, BalanceAllowanceParams, DataApiPositionsParams, DataPosition, OpenOrder,
+    OpenOrderParams,
+};
+use crate::wss::WssUserClient;
+use alloy_primitives::Address;
+use alloy_signer_local::PrivateKeySigner;
+use futures::future::{join_all, try_join_all};
+use rust_decimal::Decimal;
+use std::future::Future;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Signer and settings for one account in an [`AccountPool`]
+#[derive(Clone)]
+pub struct AccountSpec {
+    pub label: String,
+    signer: Arc<dyn PolySigner>,
+    api_creds: Option<ApiCreds>,
+    sig_type: Option<SigType>,
+    funder: Option<String>,
+}
+
+impl AccountSpec {
+    pub fn new(label: &str, signer: impl PolySigner + 'static) -> Self {
+        Self {
+            label: label.to_string(),
+            signer: Arc::new(signer),
+            api_creds: None,
+            sig_type: None,
+            funder: None,
+        }
+    }
+
+    pub fn from_private_key(label: &str, private_key: &str) -> Result<Self> {
+        let signer = private_key
+            .parse::<PrivateKeySigner>()
+            .map_err(|e| PolyError::config(format!("Invalid private key for {}: {}", label, e)))?;
+        Ok(Self::new(label, signer))
+    }
+
+    /// Use stored credentials instead of deriving them
+    pub fn with_api_creds(mut self, api_creds: ApiCreds) -> Self {
+        self.api_creds = Some(api_creds);
+        self
+    }
+
+    /// Signature type; the funder is derived from it unless set explicitly
+    pub fn with_signature_type(mut self, sig_type: SigType) -> Self {
+        self.sig_type = Some(sig_type);
+        self
+    }
+
+    pub fn with_funder(mut self, funder: &str) -> Self {
+        self.funder = Some(funder.to_string());
+        self
+    }
+
+    pub fn address(&self) -> Address {
+        self.signer.address()
+    }
+}
+
+/// One connected account
+#[derive(Clone)]
+pub struct Account {
+    pub label: String,
+    pub client: Arc<ClobClient>,
+}
+
+impl Account {
+    pub fn address(&self) -> Address {
+        self.client.address().expect("pooled clients have a signer")
+    }
+
+    /// Wallet holding the account's funds and positions
+    pub fn funder(&self) -> Address {
+        self.client
+            .funder()
+            .expect("pooled clients have an order builder")
+    }
+
+    /// An unconnected user-channel socket authenticated as this account,
+    /// on the client's WebSocket host
+    pub fn user_channel(&self) -> WssUserClient {
+        let creds = self
+            .client
+            .api_creds()
+            .expect("pooled clients have API credentials");
+        // The client's base may already include the `/ws` prefix that
+        // `WssUserClient` appends
+        let base = self.client.ws_base_url().trim_end_matches('/');
+        let base = base.strip_suffix("/ws").unwrap_or(base);
+        WssUserClient::with_url(base, creds.clone())
+    }
+}
+
+/// Result of a pool-wide call for one account
+#[derive(Debug)]
+pub struct AccountResult<T> {
+    pub label: String,
+    pub address: Address,
+    pub result: Result<T>,
+}
+
+/// Accounts that share one HTTP pool, metadata cache, server clock and rate
+/// limiter
+pub struct AccountPool {
+    accounts: Vec<Account>,
+    data_api: DataApiClient,
+}
+
+impl AccountPool {
+    /// Build a client for every spec from `template` and load or derive its
+    /// API credentials.
+    ///
+    /// `template` carries the host, chain and transport settings and must not
+    /// set a signer or private key; the funder and credentials come from each
+    /// spec. Fails if any account cannot be set up, or if two specs share a
+    /// label.
+    pub async fn connect(template: ClobClientBuilder, specs: Vec<AccountSpec>) -> Result<Self> {
+        for (i, spec) in specs.iter().enumerate() {
+            if specs[..i].iter().any(|other| other.label == spec.label) {
+                return Err(PolyError::config(format!(
+                    "Duplicate account label: {}",
+                    spec.label
+                )));
+            }
+        }
+
+        let template = template.shared()?;
+        let accounts = try_join_all(
+            specs
+                .into_iter()
+                .map(|spec| Self::connect_account(template.clone(), spec)),
+        )
+        .await?;
+        let data_api = DataApiClient::new()
+            .with_http_client(template.shared_http_client())
+            .with_rate_limiter(template.shared_rate_limiter());
+
+        Ok(Self { accounts, data_api })
+    }
+
+    async fn connect_account(template: ClobClientBuilder, spec: AccountSpec) -> Result<Account> {
+        let mut builder = template.signer(spec.signer);
+        if let Some(sig_type) = spec.sig_type {
+            builder = builder.signature_type(sig_type);
+        }
+        if let Some(funder) = &spec.funder {
+            builder = builder.funder(funder);
+        }
+        let mut client = builder.build()?;
+
+        let api_creds = match spec.api_creds {
+            Some(api_creds) => api_creds,
+            None => client.create_or_derive_api_key(None).await.map_err(|e| {
+                PolyError::auth(format!(
+                    "Failed to derive API key for {}: {}",
+                    spec.label, e
+                ))
+            })?,
+        };
+        client.set_api_creds(api_creds);
+
+        Ok(Account {
+            label: spec.label,
+            client: Arc::new(client),
+        })
+    }
+
+    /// Use a different Data API client for [`Self::positions`]
+    pub fn with_data_api(mut self, data_api: DataApiClient) -> Self {
+        self.data_api = data_api;
+        self
+    }
+
+    pub fn accounts(&self) -> &[Account] {
+        &self.accounts
+    }
+
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    /// Account by label
+    pub fn get(&self, label: &str) -> Option<&Account> {
+        self.accounts.iter().find(|account| account.label == label)
+    }
+
+    /// Account by signer or funder address
+    pub fn by_address(&self, address: Address) -> Option<&Account> {
+        self.accounts
+            .iter()
+            .find(|account| account.address() == address || account.funder() == address)
+    }
+
+    /// Account by label, as an error when missing
+    pub fn client(&self, label: &str) -> Result<&Arc<ClobClient>> {
+        self.get(label)
+            .map(|account| &account.client)
+            .ok_or_else(|| PolyError::validation(format!("Unknown account: {}", label)))
+    }
+
+    /// Run `f` against every account concurrently
+    pub async fn for_each<'a, T, F, Fut>(&'a self, f: F) -> Vec<AccountResult<T>>
+    where
+        F: Fn(&'a Account) -> Fut,
+        Fut: Future<Output = Result<T>> + 'a,
+    {
+        let results = join_all(self.accounts.iter().map(&f)).await;
+        self.accounts
+            .iter()
+            .zip(results)
+            .map(|(account, result)| AccountResult {
+                label: account.label.clone(),
+                address: account.address(),
+                result,
+            })
+            .collect()
+    }
+
+    /// Open orders of every account
+    pub async fn open_orders(
+        &self,
+        params: Option<&OpenOrderParams>,
+    ) -> Vec<AccountResult<Vec<OpenOrder>>> {
+        self.for_each(|account| account.client.get_orders(params, None))
+            .await
+    }
+
+    /// Collateral balance of every account, in collateral base units
+    /// (6 decimals) as reported by `/balance-allowance`
+    pub async fn collateral_balances(&self) -> Vec<AccountResult<Decimal>> {
+        self.for_each(|account| async move {
+            let params = BalanceAllowanceParams {
+                asset_type: Some(AssetType::COLLATERAL),
+                ..Default::default()
+            };
+            let response = account.client.get_balance_allowance(Some(params)).await?;
+            response["balance"]
+                .as_str()
+                .and_then(|balance| Decimal::from_str(balance).ok())
+                .ok_or_else(|| PolyError::parse("Missing balance in response", None))
+        })
+        .await
+    }
+
+    /// Positions held by every account's funder wallet
+    pub async fn positions(
+        &self,
+        params: Option<DataApiPositionsParams>,
+    ) -> Vec<AccountResult<Vec<DataPosition>>> {
+        let data_api = &self.data_api;
+        self.for_each(|account| {
+            let params = params.clone();
+            async move {
+                data_api
+                    .get_positions(&account.funder().to_checksum(None), params)
+                    .await
+            }
+        })
+        .await
+    }
+}
+
+/// Sum of the successful results, plus the labels of accounts that failed
+pub fn total_collateral(balances: &[AccountResult<Decimal>]) -> (Decimal, Vec<String>) {
+    let mut total = Decimal::ZERO;
+    let mut failed = Vec::new();
+    for balance in balances {
+        match &balance.result {
+            Ok(value) => total += value,
+            Err(_) => failed.push(balance.label.clone()),
+        }
+    }
+    (total, failed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::rate_limit::{EndpointFamily, RateLimit, RateLimitConfig};
+    use mockito::{Matcher, Server};
+
+    const KEY_A: &str = "0x1234567890123456789012345678901234567890123456789012345678901234";
+    const KEY_B: &str = "0x2234567890123456789012345678901234567890123456789012345678901234";
+
+    fn creds(key: &str) -> ApiCreds {
+        ApiCreds {
+            api_key: key.to_string(),
+            secret: "dGVzdF9zZWNyZXQ=".to_string(),
+            passphrase: "test_passphrase".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pool_derives_creds_and_aggregates() {
+        let mut server = Server::new_async().await;
+        let derive_mock = server
+            .mock("POST", "/auth/api-key")
+            .with_status(200)
+            .with_body(r#"{"apiKey": "derived", "secret": "dGVzdF9zZWNyZXQ=", "passphrase": "p"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let balance_a = server
+            .mock("GET", "/balance-allowance")
+            .match_query(Matcher::Any)
+            .match_header("poly_api_key", "loaded")
+            .with_status(200)
+            .with_body(r#"{"balance": "1500000", "allowances": {}}"#)
+            .create_async()
+            .await;
+        let balance_b = server
+            .mock("GET", "/balance-allowance")
+            .match_query(Matcher::Any)
+            .match_header("poly_api_key", "derived")
+            .with_status(500)
+            .create_async()
+            .await;
+        let positions_mock = server
+            .mock("GET", "/positions")
+            .match_query(Matcher::Regex("user=0x".into()))
+            .with_status(200)
+            .with_body("[]")
+            .expect(2)
+            .create_async()
+            .await;
+
+        let specs = vec![
+            AccountSpec::from_private_key("a", KEY_A)
+                .unwrap()
+                .with_api_creds(creds("loaded")),
+            AccountSpec::from_private_key("b", KEY_B)
+                .unwrap()
+                .with_signature_type(SigType::PolyGnosisSafe),
+        ];
+        let template = ClobClient::builder(&server.url())
+            .ws_base("wss://ws.example.com/ws/")
+            .rate_limits(
+                RateLimitConfig::new().with_limit(EndpointFamily::DataApi, RateLimit::new(10, 10)),
+            );
+        let pool = AccountPool::connect(template, specs).await.unwrap();
+        let data_api = pool.data_api.clone().with_base_url(&server.url());
+        let pool = pool.with_data_api(data_api);
+        derive_mock.assert_async().await;
+
+        assert_eq!(pool.len(), 2);
+        let a = pool.get("a").unwrap();
+        let b = pool.get("b").unwrap();
+        assert_eq!(b.client.api_creds().unwrap().api_key, "derived");
+        assert_eq!(pool.by_address(b.funder()).unwrap().label, "b");
+        assert_ne!(b.funder(), b.address());
+        assert!(format!("{:?}", a.user_channel()).contains("\"wss://ws.example.com/ws/user\""));
+        // Clients share one metadata cache
+        assert!(Arc::ptr_eq(
+            a.client.metadata_cache(),
+            b.client.metadata_cache()
+        ));
+
+        let balances = pool.collateral_balances().await;
+        balance_a.assert_async().await;
+        balance_b.assert_async().await;
+        assert_eq!(
+            total_collateral(&balances),
+            (Decimal::from(1_500_000), vec!["b".to_string()])
+        );
+
+        let positions = pool.positions(None).await;
+        positions_mock.assert_async().await;
+        assert!(positions.iter().all(|p| p.result.is_ok()));
+        // Data API calls draw on the limiter every account shares
+        for account in pool.accounts() {
+            let stats = account.client.rate_limit_stats(EndpointFamily::DataApi);
+            assert_eq!(stats.unwrap().requests, 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pool_rejects_duplicate_labels() {
+        let specs = vec![
+            AccountSpec::from_private_key("a", KEY_A).unwrap(),
+            AccountSpec::from_private_key("a", KEY_B).unwrap(),
+        ];
+        let result = AccountPool::connect(ClobClient::builder("http://127.0.0.1:9"), specs).await;
+        assert!(matches!(result, Err(PolyError::Config { .. })));
+    }
+}
@@ -5,16 +5,20 @@
 
 use crate::errors::{PolyError, Result};
 use crate::types::ApiCredentials;
-use alloy_primitives::{Address, U256, hex::encode_prefixed};
+use alloy_primitives::{Address, B256, Signature, U256, hex::encode_prefixed};
 use alloy_signer::SignerSync;
 use alloy_signer_local::PrivateKeySigner;
-use alloy_sol_types::{eip712_domain, sol};
+use alloy_sol_types::{Eip712Domain, SolStruct, eip712_domain, sol};
+use async_trait::async_trait;
 use base64::engine::Engine;
 use base64::engine::general_purpose::{STANDARD, URL_SAFE, URL_SAFE_NO_PAD};
 use hmac::{Hmac, Mac};
 use serde::Serialize;
 use sha2::Sha256;
 use std::collections::HashMap;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 // Header constants
@@ -25,7 +29,75 @@ const POLY_NONCE_HEADER: &str = "poly_nonce";
 const POLY_API_KEY_HEADER: &str = "poly_api_key";
 const POLY_PASS_HEADER: &str = "poly_passphrase";
 
-type Headers = HashMap<&'static str, String>;
+/// Placeholder shown instead of a secret value
+pub const REDACTED: &str = "<redacted>";
+
+/// Wrapper whose `Debug` and `Display` never show the inner value
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct Redacted<T>(pub T);
+
+impl<T> fmt::Debug for Redacted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl<T> fmt::Display for Redacted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+/// Whether a header carries a credential and must not be logged
+pub fn is_secret_header(name: &str) -> bool {
+    name.eq_ignore_ascii_case(POLY_SIG_HEADER) || name.eq_ignore_ascii_case(POLY_PASS_HEADER)
+}
+
+/// Authentication headers for one request.
+///
+/// `Debug` masks the `poly_signature` and `poly_passphrase` values, so the
+/// map can be logged as is.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Headers(HashMap<&'static str, String>);
+
+impl Deref for Headers {
+    type Target = HashMap<&'static str, String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl IntoIterator for Headers {
+    type Item = (&'static str, String);
+    type IntoIter = std::collections::hash_map::IntoIter<&'static str, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<const N: usize> From<[(&'static str, String); N]> for Headers {
+    fn from(headers: [(&'static str, String); N]) -> Self {
+        Self(HashMap::from(headers))
+    }
+}
+
+impl fmt::Debug for Headers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names: Vec<_> = self.0.keys().collect();
+        names.sort();
+        let mut map = f.debug_map();
+        for name in names {
+            if is_secret_header(name) {
+                map.entry(name, &Redacted(()));
+            } else {
+                map.entry(name, &self.0[name]);
+            }
+        }
+        map.finish()
+    }
+}
 
 fn decode_api_secret(secret: &str) -> Vec<u8> {
     base64::engine::general_purpose::URL_SAFE
@@ -69,6 +141,85 @@ sol! {
     }
 }
 
+/// Signer used for the EIP-712 payloads the CLOB expects
+///
+/// Implement [`PolySigner::sign_hash`] to plug in a remote signing service,
+/// a hardware wallet or a test double. Implementations that want to inspect
+/// the structured payload before signing can override [`PolySigner::sign_order`]
+/// and [`PolySigner::sign_clob_auth`] instead.
+#[async_trait]
+pub trait PolySigner: Send + Sync {
+    /// Address of the signing key
+    fn address(&self) -> Address;
+
+    /// Sign a 32-byte EIP-712 signing hash
+    async fn sign_hash(&self, hash: &B256) -> Result<Signature>;
+
+    /// Sign a CLOB order
+    async fn sign_order(&self, order: &Order, domain: &Eip712Domain) -> Result<Signature> {
+        self.sign_hash(&order.eip712_signing_hash(domain)).await
+    }
+
+    /// Sign the L1 `ClobAuth` attestation
+    async fn sign_clob_auth(&self, auth: &ClobAuth, domain: &Eip712Domain) -> Result<Signature> {
+        self.sign_hash(&auth.eip712_signing_hash(domain)).await
+    }
+}
+
+#[async_trait]
+impl PolySigner for PrivateKeySigner {
+    fn address(&self) -> Address {
+        alloy_signer::Signer::address(self)
+    }
+
+    async fn sign_hash(&self, hash: &B256) -> Result<Signature> {
+        self.sign_hash_sync(hash)
+            .map_err(|e| PolyError::crypto(format!("Signing failed: {}", e)))
+    }
+}
+
+#[async_trait]
+impl<S: PolySigner + ?Sized> PolySigner for Arc<S> {
+    fn address(&self) -> Address {
+        (**self).address()
+    }
+
+    async fn sign_hash(&self, hash: &B256) -> Result<Signature> {
+        (**self).sign_hash(hash).await
+    }
+
+    async fn sign_order(&self, order: &Order, domain: &Eip712Domain) -> Result<Signature> {
+        (**self).sign_order(order, domain).await
+    }
+
+    async fn sign_clob_auth(&self, auth: &ClobAuth, domain: &Eip712Domain) -> Result<Signature> {
+        (**self).sign_clob_auth(auth, domain).await
+    }
+}
+
+/// Identity without a key, for processes that only submit orders signed
+/// elsewhere.
+///
+/// L2 requests need the account address but no signature, so a client built
+/// with this signer and API credentials can call `post_order`. Signing
+/// anything fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddressOnlySigner(pub Address);
+
+#[async_trait]
+impl PolySigner for AddressOnlySigner {
+    fn address(&self) -> Address {
+        self.0
+    }
+
+    async fn sign_hash(&self, _hash: &B256) -> Result<Signature> {
+        Err(PolyError::crypto(format!(
+            "{} is an address-only signer and cannot sign",
+            self.0
+        )))
+    }
+}
+
 /// Get current Unix timestamp in seconds
 pub fn get_current_unix_time_secs() -> u64 {
     SystemTime::now()
@@ -78,8 +229,8 @@ pub fn get_current_unix_time_secs() -> u64 {
 }
 
 /// Sign CLOB authentication message using EIP-712
-pub fn sign_clob_auth_message(
-    signer: &PrivateKeySigner,
+pub async fn sign_clob_auth_message<S: PolySigner + ?Sized>(
+    signer: &S,
     timestamp: String,
     nonce: U256,
 ) -> Result<String> {
@@ -100,28 +251,35 @@ pub fn sign_clob_auth_message(
     );
 
     let signature = signer
-        .sign_typed_data_sync(&auth_struct, &domain)
+        .sign_clob_auth(&auth_struct, &domain)
+        .await
         .map_err(|e| PolyError::crypto(format!("EIP-712 signature failed: {}", e)))?;
 
     Ok(encode_prefixed(signature.as_bytes()))
 }
 
+/// EIP-712 domain of the CTF exchange at `verifying_contract`
+pub fn order_domain(chain_id: u64, verifying_contract: Address) -> Eip712Domain {
+    eip712_domain!(
+        name: "Polymarket CTF Exchange",
+        version: "1",
+        chain_id: chain_id,
+        verifying_contract: verifying_contract,
+    )
+}
+
 /// Sign order message using EIP-712
-pub fn sign_order_message(
-    signer: &PrivateKeySigner,
+pub async fn sign_order_message<S: PolySigner + ?Sized>(
+    signer: &S,
     order: Order,
     chain_id: u64,
     verifying_contract: Address,
 ) -> Result<String> {
-    let domain = eip712_domain!(
-        name: "Polymarket CTF Exchange",
-        version: "1",
-        chain_id: chain_id,
-        verifying_contract: verifying_contract,
-    );
+    let domain = order_domain(chain_id, verifying_contract);
 
     let signature = signer
-        .sign_typed_data_sync(&order, &domain)
+        .sign_order(&order, &domain)
+        .await
         .map_err(|e| PolyError::crypto(format!("Order signature failed: {}", e)))?;
 
     Ok(encode_prefixed(signature.as_bytes()))
@@ -160,13 +318,26 @@ where
 }
 
 /// Create L1 headers for authentication (using private key signature)
-pub fn create_l1_headers(signer: &PrivateKeySigner, nonce: Option<U256>) -> Result<Headers> {
-    let timestamp = get_current_unix_time_secs().to_string();
+pub async fn create_l1_headers<S: PolySigner + ?Sized>(
+    signer: &S,
+    nonce: Option<U256>,
+) -> Result<Headers> {
+    create_l1_headers_at(signer, nonce, get_current_unix_time_secs()).await
+}
+
+/// Create L1 headers stamped with an explicit Unix timestamp, e.g. one
+/// corrected for clock skew against the server
+pub async fn create_l1_headers_at<S: PolySigner + ?Sized>(
+    signer: &S,
+    nonce: Option<U256>,
+    timestamp: u64,
+) -> Result<Headers> {
+    let timestamp = timestamp.to_string();
     let nonce = nonce.unwrap_or(U256::ZERO);
-    let signature = sign_clob_auth_message(signer, timestamp.clone(), nonce)?;
+    let signature = sign_clob_auth_message(signer, timestamp.clone(), nonce).await?;
     let address = encode_prefixed(signer.address().as_slice());
 
-    Ok(HashMap::from([
+    Ok(Headers::from([
         (POLY_ADDR_HEADER, address),
         (POLY_SIG_HEADER, signature),
         (POLY_TS_HEADER, timestamp),
@@ -175,23 +346,46 @@ pub fn create_l1_headers(signer: &PrivateKeySigner, nonce: Option<U256>) -> Resu
 }
 
 /// Create L2 headers for API calls (using API key and HMAC)
-pub fn create_l2_headers<T>(
-    signer: &PrivateKeySigner,
+pub fn create_l2_headers<S, T>(
+    signer: &S,
     api_creds: &ApiCredentials,
     method: &str,
     req_path: &str,
     body: Option<&T>,
 ) -> Result<Headers>
 where
+    S: PolySigner + ?Sized,
+    T: ?Sized + Serialize,
+{
+    create_l2_headers_at(
+        signer,
+        api_creds,
+        method,
+        req_path,
+        body,
+        get_current_unix_time_secs(),
+    )
+}
+
+/// Create L2 headers stamped with an explicit Unix timestamp
+pub fn create_l2_headers_at<S, T>(
+    signer: &S,
+    api_creds: &ApiCredentials,
+    method: &str,
+    req_path: &str,
+    body: Option<&T>,
+    timestamp: u64,
+) -> Result<Headers>
+where
+    S: PolySigner + ?Sized,
     T: ?Sized + Serialize,
 {
     let address = encode_prefixed(signer.address().as_slice());
-    let timestamp = get_current_unix_time_secs();
 
     let hmac_signature =
         build_hmac_signature(&api_creds.secret, timestamp, method, req_path, body)?;
 
-    Ok(HashMap::from([
+    Ok(Headers::from([
         (POLY_ADDR_HEADER, address),
         (POLY_SIG_HEADER, hmac_signature),
         (POLY_TS_HEADER, timestamp.to_string()),
@@ -341,15 +535,15 @@ mod tests {
         assert_eq!(signature, PY_ORDER_SIGNATURE);
     }
 
-    #[test]
-    fn test_create_l1_headers() {
+    #[tokio::test]
+    async fn test_create_l1_headers() {
         use alloy_primitives::U256;
         use alloy_signer_local::PrivateKeySigner;
 
         let private_key = "0x1234567890123456789012345678901234567890123456789012345678901234";
         let signer: PrivateKeySigner = private_key.parse().expect("Valid private key");
 
-        let result = create_l1_headers(&signer, Some(U256::from(12345)));
+        let result = create_l1_headers(&signer, Some(U256::from(12345))).await;
         assert!(result.is_ok());
 
         let headers = result.unwrap();
@@ -359,16 +553,20 @@ mod tests {
         assert!(headers.contains_key("poly_nonce"));
     }
 
-    #[test]
-    fn test_create_l1_headers_different_nonces() {
+    #[tokio::test]
+    async fn test_create_l1_headers_different_nonces() {
         use alloy_primitives::U256;
         use alloy_signer_local::PrivateKeySigner;
 
         let private_key = "0x1234567890123456789012345678901234567890123456789012345678901234";
         let signer: PrivateKeySigner = private_key.parse().expect("Valid private key");
 
-        let headers_1 = create_l1_headers(&signer, Some(U256::from(12345))).unwrap();
-        let headers_2 = create_l1_headers(&signer, Some(U256::from(54321))).unwrap();
+        let headers_1 = create_l1_headers(&signer, Some(U256::from(12345)))
+            .await
+            .unwrap();
+        let headers_2 = create_l1_headers(&signer, Some(U256::from(54321)))
+            .await
+            .unwrap();
 
         // Different nonces should produce different signatures
         assert_ne!(
@@ -393,7 +591,7 @@ mod tests {
             passphrase: "test_passphrase".to_string(),
         };
 
-        let result = create_l2_headers::<String>(&signer, &api_creds, "/test", "GET", None);
+        let result = create_l2_headers::<_, String>(&signer, &api_creds, "/test", "GET", None);
         assert!(result.is_ok());
 
         let headers = result.unwrap();
@@ -407,7 +605,33 @@ mod tests {
     }
 
     #[test]
-    fn test_eip712_signature_format() {
+    fn test_debug_redacts_secrets() {
+        use alloy_signer_local::PrivateKeySigner;
+
+        let signer: PrivateKeySigner =
+            "0x1234567890123456789012345678901234567890123456789012345678901234"
+                .parse()
+                .unwrap();
+        let api_creds = ApiCredentials {
+            api_key: "test_key".to_string(),
+            secret: "test_secret".to_string(),
+            passphrase: "test_passphrase".to_string(),
+        };
+        let headers =
+            create_l2_headers::<_, String>(&signer, &api_creds, "GET", "/test", None).unwrap();
+        let signature = headers.get("poly_signature").unwrap().clone();
+
+        let logged = format!("{:?} {:?}", headers, api_creds);
+        assert!(logged.contains("test_key"));
+        assert!(logged.contains(REDACTED));
+        for secret in ["test_secret", "test_passphrase", signature.as_str()] {
+            assert!(!logged.contains(secret), "{} leaked", secret);
+        }
+        assert_eq!(format!("{}", Redacted("hunter2")), REDACTED);
+    }
+
+    #[tokio::test]
+    async fn test_eip712_signature_format() {
         use alloy_primitives::U256;
         use alloy_signer_local::PrivateKeySigner;
 
@@ -415,7 +639,7 @@ mod tests {
         let signer: PrivateKeySigner = private_key.parse().expect("Valid private key");
 
         // Test that we can create and sign EIP-712 messages
-        let result = create_l1_headers(&signer, Some(U256::from(12345)));
+        let result = create_l1_headers(&signer, Some(U256::from(12345))).await;
         assert!(result.is_ok());
 
         let headers = result.unwrap();
@@ -426,6 +650,61 @@ mod tests {
         assert_eq!(signature.len(), 132); // 0x + 130 hex chars = 132 total
     }
 
+    /// Signer that only knows how to sign raw hashes, like a remote service
+    struct RemoteSigner {
+        inner: PrivateKeySigner,
+    }
+
+    #[async_trait]
+    impl PolySigner for RemoteSigner {
+        fn address(&self) -> Address {
+            PolySigner::address(&self.inner)
+        }
+
+        async fn sign_hash(&self, hash: &B256) -> Result<Signature> {
+            self.inner.sign_hash(hash).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_signer_matches_local_key() {
+        let private_key = "0x1234567890123456789012345678901234567890123456789012345678901234";
+        let local: PrivateKeySigner = private_key.parse().expect("Valid private key");
+        let remote: Arc<dyn PolySigner> = Arc::new(RemoteSigner {
+            inner: local.clone(),
+        });
+
+        let expected = sign_clob_auth_message(&local, "1700000000".to_string(), U256::ZERO)
+            .await
+            .unwrap();
+        let actual = sign_clob_auth_message(&remote, "1700000000".to_string(), U256::ZERO)
+            .await
+            .unwrap();
+        assert_eq!(expected, actual);
+
+        let order = Order {
+            salt: U256::from(1),
+            maker: PolySigner::address(&local),
+            signer: PolySigner::address(&local),
+            taker: Address::ZERO,
+            tokenId: U256::from(1111),
+            makerAmount: U256::from(500),
+            takerAmount: U256::from(5000),
+            expiration: U256::ZERO,
+            nonce: U256::ZERO,
+            feeRateBps: U256::ZERO,
+            side: 0,
+            signatureType: 0,
+        };
+        let expected = sign_order_message(&local, order.clone(), 137, Address::ZERO)
+            .await
+            .unwrap();
+        let actual = sign_order_message(&remote, order, 137, Address::ZERO)
+            .await
+            .unwrap();
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     fn test_timestamp_generation() {
         let ts1 = get_current_unix_time_secs();
@@ -0,0 +1,214 @@
+//! Per-token market metadata cache
+//!
+//! Signing an order needs the market's tick size, neg-risk flag and fee rate.
+//! Caching them lets a warmed client sign orders without any HTTP round trip.
+
+use crate::wss::WssMarketEvent;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+/// How long each kind of metadata stays fresh
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MetadataTtl {
+    pub tick_size: Duration,
+    pub neg_risk: Duration,
+    pub fee_rate: Duration,
+}
+
+impl Default for MetadataTtl {
+    fn default() -> Self {
+        Self {
+            // Tick sizes change mid-market; the others are effectively static
+            tick_size: Duration::from_secs(5 * 60),
+            neg_risk: Duration::from_secs(24 * 60 * 60),
+            fee_rate: Duration::from_secs(60 * 60),
+        }
+    }
+}
+
+impl MetadataTtl {
+    /// Use the same TTL for every field
+    pub fn uniform(ttl: Duration) -> Self {
+        Self {
+            tick_size: ttl,
+            neg_risk: ttl,
+            fee_rate: ttl,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Cached<T> {
+    value: T,
+    stored_at: Instant,
+}
+
+impl<T: Copy> Cached<T> {
+    fn new(value: T) -> Self {
+        Self {
+            value,
+            stored_at: Instant::now(),
+        }
+    }
+
+    fn fresh(&self, ttl: Duration) -> Option<T> {
+        (self.stored_at.elapsed() < ttl).then_some(self.value)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct MarketMetadata {
+    tick_size: Option<Cached<Decimal>>,
+    neg_risk: Option<Cached<bool>>,
+    fee_rate_bps: Option<Cached<u32>>,
+}
+
+/// Thread-safe cache of tick size, neg-risk and fee rate keyed by token id.
+///
+/// Share one instance between clients with
+/// [`ClobClientBuilder::metadata_cache`](crate::client::ClobClientBuilder::metadata_cache).
+#[derive(Debug, Default)]
+pub struct MarketMetadataCache {
+    ttl: MetadataTtl,
+    entries: RwLock<HashMap<String, MarketMetadata>>,
+}
+
+impl MarketMetadataCache {
+    pub fn new(ttl: MetadataTtl) -> Self {
+        Self {
+            ttl,
+            entries: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub fn ttl(&self) -> MetadataTtl {
+        self.ttl
+    }
+
+    fn read<T>(&self, token_id: &str, f: impl FnOnce(&MarketMetadata) -> Option<T>) -> Option<T> {
+        let entries = self.entries.read().unwrap_or_else(|e| e.into_inner());
+        entries.get(token_id).and_then(f)
+    }
+
+    fn write(&self, token_id: &str, f: impl FnOnce(&mut MarketMetadata)) {
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        f(entries.entry(token_id.to_string()).or_default());
+    }
+
+    /// Cached tick size, if present and not expired
+    pub fn tick_size(&self, token_id: &str) -> Option<Decimal> {
+        self.read(token_id, |m| m.tick_size?.fresh(self.ttl.tick_size))
+    }
+
+    /// Cached neg-risk flag, if present and not expired
+    pub fn neg_risk(&self, token_id: &str) -> Option<bool> {
+        self.read(token_id, |m| m.neg_risk?.fresh(self.ttl.neg_risk))
+    }
+
+    /// Cached fee rate in basis points, if present and not expired
+    pub fn fee_rate_bps(&self, token_id: &str) -> Option<u32> {
+        self.read(token_id, |m| m.fee_rate_bps?.fresh(self.ttl.fee_rate))
+    }
+
+    pub fn set_tick_size(&self, token_id: &str, tick_size: Decimal) {
+        self.write(token_id, |m| m.tick_size = Some(Cached::new(tick_size)));
+    }
+
+    pub fn set_neg_risk(&self, token_id: &str, neg_risk: bool) {
+        self.write(token_id, |m| m.neg_risk = Some(Cached::new(neg_risk)));
+    }
+
+    pub fn set_fee_rate_bps(&self, token_id: &str, fee_rate_bps: u32) {
+        self.write(token_id, |m| {
+            m.fee_rate_bps = Some(Cached::new(fee_rate_bps))
+        });
+    }
+
+    /// Whether tick size and neg-risk are both cached, i.e. orders for this
+    /// token can be signed without hitting the network
+    pub fn is_warm(&self, token_id: &str) -> bool {
+        self.tick_size(token_id).is_some() && self.neg_risk(token_id).is_some()
+    }
+
+    /// Drop everything cached for a token
+    pub fn invalidate(&self, token_id: &str) {
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        entries.remove(token_id);
+    }
+
+    /// Drop every cached entry
+    pub fn clear(&self) {
+        let mut entries = self.entries.write().unwrap_or_else(|e| e.into_inner());
+        entries.clear();
+    }
+
+    /// Keep the cache in sync with the market channel.
+    ///
+    /// A `tick_size_change` replaces the cached tick size with the new value so
+    /// later orders are signed against the current grid. Other events are
+    /// ignored.
+    pub fn apply_market_event(&self, event: &WssMarketEvent) {
+        if let WssMarketEvent::TickSizeChange(change) = event {
+            self.set_tick_size(&change.asset_id, change.new_tick_size);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wss::TickSizeChangeMessage;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_cache_roundtrip() {
+        let cache = MarketMetadataCache::default();
+        assert!(!cache.is_warm("1"));
+
+        cache.set_tick_size("1", Decimal::from_str("0.01").unwrap());
+        cache.set_neg_risk("1", true);
+        cache.set_fee_rate_bps("1", 10);
+
+        assert!(cache.is_warm("1"));
+        assert_eq!(
+            cache.tick_size("1"),
+            Some(Decimal::from_str("0.01").unwrap())
+        );
+        assert_eq!(cache.neg_risk("1"), Some(true));
+        assert_eq!(cache.fee_rate_bps("1"), Some(10));
+
+        cache.invalidate("1");
+        assert_eq!(cache.tick_size("1"), None);
+    }
+
+    #[test]
+    fn test_cache_expires_entries() {
+        let cache = MarketMetadataCache::new(MetadataTtl::uniform(Duration::ZERO));
+        cache.set_tick_size("1", Decimal::from_str("0.01").unwrap());
+        assert_eq!(cache.tick_size("1"), None);
+    }
+
+    #[test]
+    fn test_tick_size_change_updates_cache() {
+        let cache = MarketMetadataCache::default();
+        cache.set_tick_size("1", Decimal::from_str("0.01").unwrap());
+
+        let event = WssMarketEvent::TickSizeChange(TickSizeChangeMessage {
+            event_type: "tick_size_change".into(),
+            asset_id: "1".into(),
+            market: "0xabc".into(),
+            old_tick_size: Decimal::from_str("0.01").unwrap(),
+            new_tick_size: Decimal::from_str("0.001").unwrap(),
+            side: "BUY".into(),
+            timestamp: "0".into(),
+        });
+        cache.apply_market_event(&event);
+
+        assert_eq!(
+            cache.tick_size("1"),
+            Some(Decimal::from_str("0.001").unwrap())
+        );
+    }
+}
@@ -3,15 +3,22 @@
 //! This module provides a production-ready client for interacting with
 //! Polymarket, optimized for high-frequency trading environments.
 
-use crate::auth::{create_l1_headers, create_l2_headers};
+use crate::auth::{PolySigner, Redacted, create_l1_headers_at, create_l2_headers_at};
+use crate::cache::MarketMetadataCache;
+use crate::config::NetworkConfig;
 use crate::errors::{PolyError, Result};
-use crate::types::{OrderOptions, PostOrder, SignedOrderRequest};
+use crate::orders::SigType;
+use crate::types::{OrderOptions, Page, PostOrder, SignedOrderRequest};
+use crate::utils::rate_limit::{EndpointFamily, RateLimitConfig, RateLimitStats, RateLimiter};
+use crate::utils::retry::RetryConfig;
+use crate::utils::time::{ClockSample, ServerClock};
 use alloy_primitives::{Address, U256};
 use alloy_signer_local::PrivateKeySigner;
 use async_trait::async_trait;
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD as BASE64_ENGINE;
 use chrono::{Duration, Utc};
+use futures::{Stream, TryStreamExt};
 use reqwest::Client;
 use reqwest::header::HeaderName;
 use reqwest::{Method, RequestBuilder};
@@ -19,14 +26,20 @@ use rust_decimal::Decimal;
 use rust_decimal::prelude::FromPrimitive;
 use serde::de::DeserializeOwned;
 use serde_json::{self, Value};
-use std::env;
 use std::str::FromStr;
+use std::sync::Arc;
+use zeroize::Zeroizing;
 
 const DEFAULT_GAMMA_BASE: &str = "https://gamma-api.polymarket.com";
 const DEFAULT_WS_BASE: &str = "wss://ws-subscriptions-clob.polymarket.com/ws/";
 const DEFAULT_RTDS_BASE: &str = "wss://ws-live-data.polymarket.com";
 const DEFAULT_DATA_API_BASE: &str = "https://data-api.polymarket.com";
 const GAMMA_MARKETS_LIMIT: u32 = 50;
+const INITIAL_CURSOR: &str = "MA==";
+const END_CURSOR: &str = "LTE=";
+const CLOCK_SYNC_SAMPLES: usize = 3;
+const MAX_BATCH_ORDERS: usize = 15;
+const GTD_EXPIRATION_MARGIN_SECS: u64 = 60;
 
 // Re-export types for compatibility
 pub use crate::types::{ApiCredentials as ApiCreds, OrderType, Side};
@@ -62,15 +75,140 @@ impl Default for OrderArgs {
     }
 }
 
+/// Funder for `sig_type` on a known chain
+fn derive_funder(owner: Address, sig_type: SigType, chain_id: u64) -> Result<Address> {
+    if sig_type == SigType::Eoa {
+        return Ok(owner);
+    }
+    let network = NetworkConfig::for_chain(chain_id).ok_or_else(|| {
+        PolyError::config(format!(
+            "Cannot derive a funder on unknown chain {}; set it explicitly",
+            chain_id
+        ))
+    })?;
+    crate::wallet::derive_funder(owner, sig_type, &network)
+}
+
+fn parse_server_time(text: &str) -> Result<u64> {
+    text.trim()
+        .parse::<u64>()
+        .map_err(|e| PolyError::parse(format!("Invalid timestamp format: {}", e), None))
+}
+
+/// Take `CLOCK_SYNC_SAMPLES` readings of `GET /time` and record them on `clock`
+async fn sync_server_clock(
+    http_client: &Client,
+    url: &str,
+    clock: &ServerClock,
+) -> Result<ClockSample> {
+    let mut samples = Vec::with_capacity(CLOCK_SYNC_SAMPLES);
+    for _ in 0..CLOCK_SYNC_SAMPLES {
+        let sent_millis = crate::utils::time::now_millis();
+        let response = http_client.get(url).send().await?;
+        let received_millis = crate::utils::time::now_millis();
+        if !response.status().is_success() {
+            return Err(response_error(response, "Failed to get server time").await);
+        }
+        samples.push(ClockSample {
+            sent_millis,
+            received_millis,
+            server_secs: parse_server_time(&response.text().await?)?,
+        });
+    }
+
+    let best = clock
+        .record(&samples)
+        .expect("at least one clock sample was taken");
+    tracing::debug!(
+        offset_millis = best.offset_millis(),
+        round_trip_millis = best.round_trip().as_millis() as u64,
+        "synced server clock"
+    );
+    Ok(best)
+}
+
+/// Parse a `Retry-After` header given either as delay-seconds or an HTTP date.
+fn parse_retry_after(headers: &reqwest::header::HeaderMap) -> Option<std::time::Duration> {
+    let value = headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(std::time::Duration::from_secs(secs));
+    }
+
+    let at = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    (at.with_timezone(&Utc) - Utc::now()).to_std().ok()
+}
+
+/// Map a non-success response to an error.
+///
+/// 429s become [`PolyError::RateLimit`] with the server's `Retry-After` hint;
+/// other bodies are decoded by [`crate::decode::decode_error_response`].
+async fn response_error(response: reqwest::Response, message: &str) -> PolyError {
+    if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return PolyError::RateLimit {
+            message: message.to_string(),
+            retry_after: parse_retry_after(response.headers()),
+        };
+    }
+
+    let status = response.status().as_u16();
+    let body = response.text().await.unwrap_or_default();
+    crate::decode::decode_error_response(status, &body, message)
+}
+
+/// Order submission body with each order's `owner` (the API key) masked,
+/// for logging
+fn redact_order_owner<T: serde::Serialize>(body: &T) -> Value {
+    let mut body = serde_json::to_value(body).unwrap_or_default();
+    let orders: Vec<&mut Value> = match &mut body {
+        Value::Array(orders) => orders.iter_mut().collect(),
+        order => vec![order],
+    };
+    for order in orders {
+        if let Some(owner) = order.get_mut("owner") {
+            *owner = Value::from(crate::auth::REDACTED);
+        }
+    }
+    body
+}
+
+/// Turn a page fetcher into a stream that follows `next_cursor` until the
+/// listing is exhausted or a request fails.
+fn paginate<'a, T, F, Fut>(cursor: String, fetch: F) -> impl Stream<Item = Result<Page<T>>> + 'a
+where
+    T: 'a,
+    F: FnMut(String) -> Fut + 'a,
+    Fut: std::future::Future<Output = Result<Page<T>>> + 'a,
+{
+    futures::stream::try_unfold((Some(cursor), fetch), |(cursor, mut fetch)| async move {
+        let Some(cursor) = cursor else {
+            return Ok(None);
+        };
+        let page = fetch(cursor).await?;
+        let next_cursor = page.next_cursor.clone();
+        Ok(Some((page, (next_cursor, fetch))))
+    })
+}
+
 /// Client for Polymarket's public data API.
 ///
 /// This client is intentionally light-weight and only serves the
-/// public `/value` and `/positions` endpoints, which do not require
-/// authentication.
+/// public Data API endpoints, none of which require authentication.
 #[derive(Debug, Clone)]
 pub struct DataApiClient {
     http_client: Client,
     base_url: String,
+    rate_limiter: Arc<RateLimiter>,
+}
+
+impl Default for DataApiClient {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DataApiClient {
@@ -79,6 +217,7 @@ impl DataApiClient {
         Self {
             http_client: Client::new(),
             base_url: DEFAULT_DATA_API_BASE.to_string(),
+            rate_limiter: Arc::default(),
         }
     }
 
@@ -88,6 +227,30 @@ impl DataApiClient {
         self
     }
 
+    /// Reuse an existing HTTP client (and its connection pool).
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.http_client = client;
+        self
+    }
+
+    /// Draw on an existing limiter's [`EndpointFamily::DataApi`] budget.
+    pub fn with_rate_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = limiter;
+        self
+    }
+
+    /// Throttle requests using the [`EndpointFamily::DataApi`] limit.
+    pub fn with_rate_limits(mut self, config: &RateLimitConfig) -> Result<Self> {
+        config.validate()?;
+        self.rate_limiter = Arc::new(RateLimiter::new(config));
+        Ok(self)
+    }
+
+    /// Delay metrics for the Data API limiter, if one is configured.
+    pub fn rate_limit_stats(&self) -> Option<RateLimitStats> {
+        self.rate_limiter.stats(EndpointFamily::DataApi)
+    }
+
     fn data_api_url(&self, path: &str) -> String {
         let base = self.base_url.trim_end_matches('/');
         let path = path.trim_start_matches('/');
@@ -105,6 +268,8 @@ impl DataApiClient {
         &self,
         user: &str,
     ) -> Result<Vec<crate::types::DataPositionValue>> {
+        self.rate_limiter.acquire(EndpointFamily::DataApi).await;
+
         let response = self
             .http_client
             .get(self.data_api_url("value"))
@@ -113,10 +278,7 @@ impl DataApiClient {
             .await?;
 
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to fetch total positions value",
-            ));
+            return Err(response_error(response, "Failed to fetch total positions value").await);
         }
 
         response
@@ -134,6 +296,8 @@ impl DataApiClient {
         user: &str,
         params: Option<crate::types::DataApiPositionsParams>,
     ) -> Result<Vec<crate::types::DataPosition>> {
+        self.rate_limiter.acquire(EndpointFamily::DataApi).await;
+
         let params = params.unwrap_or_default();
         let mut query = params.to_query_params();
         query.push(("user", user.to_string()));
@@ -146,10 +310,7 @@ impl DataApiClient {
             .await?;
 
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to fetch positions",
-            ));
+            return Err(response_error(response, "Failed to fetch positions").await);
         }
 
         response
@@ -157,6 +318,102 @@ impl DataApiClient {
             .await
             .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
     }
+
+    /// Fetch recent trades across the platform, or for specific markets,
+    /// events or users.
+    pub async fn get_trades(
+        &self,
+        params: Option<crate::types::DataApiTradesParams>,
+    ) -> Result<Vec<crate::types::DataTrade>> {
+        let query = params.unwrap_or_default().to_query_params();
+        self.get_json("trades", &query, "Failed to fetch trades")
+            .await
+    }
+
+    /// Fetch the on-chain activity (trades, splits, merges, redemptions,
+    /// rewards and conversions) of a wallet.
+    pub async fn get_activity(
+        &self,
+        user: &str,
+        params: Option<crate::types::DataApiActivityParams>,
+    ) -> Result<Vec<crate::types::DataActivity>> {
+        let mut query = params.unwrap_or_default().to_query_params();
+        query.push(("user", user.to_string()));
+        self.get_json("activity", &query, "Failed to fetch activity")
+            .await
+    }
+
+    /// Fetch the top holders of each outcome token in the given markets.
+    pub async fn get_holders(
+        &self,
+        condition_ids: &[String],
+        params: Option<crate::types::DataApiHoldersParams>,
+    ) -> Result<Vec<crate::types::DataTokenHolders>> {
+        let mut query = params.unwrap_or_default().to_query_params();
+        query.push(("market", condition_ids.join(",")));
+        self.get_json("holders", &query, "Failed to fetch holders")
+            .await
+    }
+
+    /// Fetch the open interest of the given markets.
+    pub async fn get_open_interest(
+        &self,
+        condition_ids: &[String],
+    ) -> Result<Vec<crate::types::DataOpenInterest>> {
+        let query = [("market", condition_ids.join(","))];
+        self.get_json("oi", &query, "Failed to fetch open interest")
+            .await
+    }
+
+    /// Fetch the live volume of an event, broken down by market.
+    pub async fn get_live_volume(&self, event_id: &str) -> Result<crate::types::DataLiveVolume> {
+        let query = [("id", event_id.to_string())];
+        let mut volumes: Vec<crate::types::DataLiveVolume> = self
+            .get_json("live-volume", &query, "Failed to fetch live volume")
+            .await?;
+        if volumes.is_empty() {
+            return Err(PolyError::parse(
+                format!("No live volume returned for event {}", event_id),
+                None,
+            ));
+        }
+        Ok(volumes.swap_remove(0))
+    }
+
+    /// Fetch the trader leaderboard.
+    pub async fn get_leaderboard(
+        &self,
+        params: Option<crate::types::DataApiLeaderboardParams>,
+    ) -> Result<Vec<crate::types::LeaderboardEntry>> {
+        let query = params.unwrap_or_default().to_query_params();
+        self.get_json("v1/leaderboard", &query, "Failed to fetch leaderboard")
+            .await
+    }
+
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+        context: &str,
+    ) -> Result<T> {
+        self.rate_limiter.acquire(EndpointFamily::DataApi).await;
+
+        let response = self
+            .http_client
+            .get(self.data_api_url(path))
+            .query(query)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(response_error(response, context).await);
+        }
+
+        response
+            .json::<T>()
+            .await
+            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+    }
 }
 
 /// Main client for interacting with Polymarket API
@@ -167,234 +424,818 @@ pub struct ClobClient {
     ws_base_url: String,
     rtds_base_url: String,
     chain_id: u64,
-    signer: Option<PrivateKeySigner>,
+    signer: Option<Arc<dyn PolySigner>>,
     api_creds: Option<ApiCreds>,
     order_builder: Option<crate::orders::OrderBuilder>,
+    rate_limiter: Arc<RateLimiter>,
+    retry_config: Option<RetryConfig>,
+    metadata: Arc<MarketMetadataCache>,
+    clock: Arc<ServerClock>,
 }
 
-impl ClobClient {
-    /// Create a new client
+/// Fallible builder for [`ClobClient`].
+///
+/// Unlike the convenience constructors, every input is validated in
+/// [`ClobClientBuilder::build`] and reported as a [`PolyError`] instead of
+/// panicking.
+///
+/// ```no_run
+/// # use polysqueeze::client::ClobClient;
+/// # use std::time::Duration;
+/// # fn main() -> polysqueeze::Result<()> {
+/// let client = ClobClient::builder("https://clob.polymarket.com")
+///     .chain_id(137)
+///     .private_key("0x...")
+///     .timeout(Duration::from_secs(10))
+///     .user_agent("my-bot/1.0")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ClobClientBuilder {
+    host: String,
+    chain_id: u64,
+    private_key: Option<Zeroizing<String>>,
+    signer: Option<Arc<dyn PolySigner>>,
+    api_creds: Option<ApiCreds>,
+    funder: Option<String>,
+    sig_type: Option<SigType>,
+    gamma_base_url: String,
+    ws_base_url: String,
+    rtds_base_url: String,
+    timeout: Option<std::time::Duration>,
+    connect_timeout: Option<std::time::Duration>,
+    user_agent: Option<String>,
+    proxy: Option<String>,
+    http_client: Option<Client>,
+    rate_limits: RateLimitConfig,
+    retry_config: Option<RetryConfig>,
+    metadata_cache: Option<Arc<MarketMetadataCache>>,
+    server_clock: Option<Arc<ServerClock>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+}
+
+impl std::fmt::Debug for ClobClientBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClobClientBuilder")
+            .field("host", &self.host)
+            .field("chain_id", &self.chain_id)
+            .field("private_key", &self.private_key.as_ref().map(Redacted))
+            .field("signer", &self.signer.as_ref().map(|s| s.address()))
+            .field("api_creds", &self.api_creds)
+            .field("funder", &self.funder)
+            .field("sig_type", &self.sig_type)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ClobClientBuilder {
+    /// Start a builder for the given CLOB host
     pub fn new(host: &str) -> Self {
         Self {
-            http_client: Client::new(),
-            base_url: host.to_string(),
-            gamma_base_url: DEFAULT_GAMMA_BASE.to_string(),
-            ws_base_url: DEFAULT_WS_BASE.to_string(),
-            rtds_base_url: DEFAULT_RTDS_BASE.to_string(),
+            host: host.to_string(),
             chain_id: 137, // Default to Polygon
+            private_key: None,
             signer: None,
             api_creds: None,
-            order_builder: None,
+            funder: None,
+            sig_type: None,
+            gamma_base_url: DEFAULT_GAMMA_BASE.to_string(),
+            ws_base_url: DEFAULT_WS_BASE.to_string(),
+            rtds_base_url: DEFAULT_RTDS_BASE.to_string(),
+            timeout: None,
+            connect_timeout: None,
+            user_agent: None,
+            proxy: None,
+            http_client: None,
+            rate_limits: RateLimitConfig::default(),
+            retry_config: None,
+            metadata_cache: None,
+            server_clock: None,
+            rate_limiter: None,
         }
     }
 
-    fn encode_cursor(cursor: u64) -> String {
-        BASE64_ENGINE.encode(cursor.to_string())
+    /// Set the chain id used for signing (defaults to Polygon mainnet)
+    pub fn chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = chain_id;
+        self
     }
 
-    fn decode_cursor(cursor: &str) -> Option<u64> {
-        BASE64_ENGINE
-            .decode(cursor)
-            .ok()
-            .and_then(|bytes| String::from_utf8(bytes).ok())
-            .and_then(|s| s.parse::<u64>().ok())
+    /// Hex-encoded private key; parsed when the client is built
+    pub fn private_key(mut self, private_key: &str) -> Self {
+        self.private_key = Some(Zeroizing::new(private_key.to_string()));
+        self
     }
 
-    fn build_url(base: &str, path: &str) -> String {
-        let base = base.trim_end_matches('/');
-        let path = path.trim_start_matches('/');
-        if path.is_empty() {
-            base.to_string()
-        } else {
-            format!("{}/{}", base, path)
-        }
+    /// Use any [`PolySigner`], e.g. a local key or a remote signing service
+    pub fn signer(mut self, signer: impl PolySigner + 'static) -> Self {
+        self.signer = Some(Arc::new(signer));
+        self
     }
 
-    fn clob_url(&self, path: &str) -> String {
-        Self::build_url(&self.base_url, path)
+    /// API credentials for L2 authenticated endpoints
+    pub fn api_creds(mut self, api_creds: ApiCreds) -> Self {
+        self.api_creds = Some(api_creds);
+        self
     }
 
-    fn gamma_url(&self, path: &str) -> String {
-        Self::build_url(&self.gamma_base_url, path)
+    /// Funder/maker address for signed orders; requires [`Self::signature_type`].
+    ///
+    /// When omitted, proxy and Safe signature types use the wallet derived
+    /// from the signer (see [`crate::wallet`]).
+    pub fn funder(mut self, funder: &str) -> Self {
+        self.funder = Some(funder.to_string());
+        self
     }
 
-    /// Create a client with L1 headers (for authentication)
-    pub fn with_l1_headers(host: &str, private_key: &str, chain_id: u64) -> Self {
-        let signer = private_key
-            .parse::<PrivateKeySigner>()
-            .expect("Invalid private key");
-
-        let order_builder = crate::orders::OrderBuilder::new(signer.clone(), None, None);
+    /// Signature type for signed orders
+    pub fn signature_type(mut self, sig_type: SigType) -> Self {
+        self.sig_type = Some(sig_type);
+        self
+    }
 
-        Self {
-            http_client: Client::new(),
-            base_url: host.to_string(),
-            gamma_base_url: DEFAULT_GAMMA_BASE.to_string(),
-            ws_base_url: DEFAULT_WS_BASE.to_string(),
-            rtds_base_url: DEFAULT_RTDS_BASE.to_string(),
-            chain_id,
-            signer: Some(signer),
-            api_creds: None,
-            order_builder: Some(order_builder),
-        }
+    /// Override the Gamma API base URL
+    pub fn gamma_base(mut self, url: &str) -> Self {
+        self.gamma_base_url = url.to_string();
+        self
     }
 
-    /// Create a client with L2 headers (for API key authentication)
-    pub fn with_l2_headers(
-        host: &str,
-        private_key: &str,
-        chain_id: u64,
-        api_creds: ApiCreds,
-    ) -> Self {
-        let signer = private_key
-            .parse::<PrivateKeySigner>()
-            .expect("Invalid private key");
+    /// Override the WebSocket base URL
+    pub fn ws_base(mut self, url: &str) -> Self {
+        self.ws_base_url = url.to_string();
+        self
+    }
 
-        let order_builder = crate::orders::OrderBuilder::new(signer.clone(), None, None);
+    /// Override the RTDS base URL
+    pub fn rtds_base(mut self, url: &str) -> Self {
+        self.rtds_base_url = url.to_string();
+        self
+    }
 
-        Self {
-            http_client: Client::new(),
-            base_url: host.to_string(),
-            gamma_base_url: DEFAULT_GAMMA_BASE.to_string(),
-            ws_base_url: DEFAULT_WS_BASE.to_string(),
-            rtds_base_url: DEFAULT_RTDS_BASE.to_string(),
-            chain_id,
-            signer: Some(signer),
-            api_creds: Some(api_creds),
-            order_builder: Some(order_builder),
-        }
+    /// Total timeout applied to every HTTP request
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
     }
 
-    /// Set API credentials
-    pub fn set_api_creds(&mut self, api_creds: ApiCreds) {
-        self.api_creds = Some(api_creds);
+    /// Timeout for establishing HTTP connections
+    pub fn connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
     }
 
-    /// Override the funder/maker address used when creating signed orders.
-    pub fn set_funder(&mut self, funder: &str) -> Result<()> {
-        let address = Address::from_str(funder)
-            .map_err(|err| PolyError::validation(format!("Invalid funder address: {}", err)))?;
+    /// `User-Agent` header sent with every HTTP request
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
 
-        let order_builder = self
-            .order_builder
-            .as_mut()
-            .ok_or_else(|| PolyError::config("Order builder not initialized"))?;
+    /// Route all HTTP traffic through the given proxy URL
+    pub fn proxy(mut self, proxy: &str) -> Self {
+        self.proxy = Some(proxy.to_string());
+        self
+    }
 
-        order_builder.set_funder(address);
-        Ok(())
+    /// Use a caller-supplied HTTP client.
+    ///
+    /// The client is used as-is, so it cannot be combined with `timeout`,
+    /// `connect_timeout`, `user_agent` or `proxy`.
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.http_client = Some(client);
+        self
     }
 
-    /// Override the Gamma API base URL
-    pub fn with_gamma_base(mut self, url: &str) -> Self {
-        self.gamma_base_url = url.to_string();
+    /// Per-endpoint-family rate limits; requests wait for capacity
+    pub fn rate_limits(mut self, config: RateLimitConfig) -> Self {
+        self.rate_limits = config;
+        self.rate_limiter = None;
         self
     }
 
-    /// Override the WebSocket base URL
-    pub fn with_ws_base(mut self, url: &str) -> Self {
-        self.ws_base_url = url.to_string();
+    /// Retry idempotent GET requests on network errors, 429s and 5xx
+    /// responses. Order placement and cancellation are never retried.
+    pub fn retry(mut self, config: RetryConfig) -> Self {
+        self.retry_config = Some(config);
         self
     }
 
-    /// Override the RTDS base URL
-    pub fn with_rtds_base(mut self, url: &str) -> Self {
-        self.rtds_base_url = url.to_string();
+    /// Use an existing metadata cache, e.g. one shared with other clients.
+    /// Each client gets its own cache with default TTLs otherwise.
+    pub fn metadata_cache(mut self, cache: Arc<MarketMetadataCache>) -> Self {
+        self.metadata_cache = Some(cache);
         self
     }
 
-    /// Test basic connectivity
-    pub async fn get_ok(&self) -> bool {
-        match self.http_client.get(self.clob_url("ok")).send().await {
-            Ok(response) => response.status().is_success(),
-            Err(_) => false,
-        }
+    /// Share a server clock estimate, e.g. between clients of one host
+    pub fn server_clock(mut self, clock: Arc<ServerClock>) -> Self {
+        self.server_clock = Some(clock);
+        self
     }
 
-    /// Get server time
-    pub async fn get_server_time(&self) -> Result<u64> {
-        let response = self.http_client.get(self.clob_url("time")).send().await?;
+    /// Share a rate limiter, so that several clients draw on one budget.
+    /// Takes precedence over [`Self::rate_limits`].
+    pub fn rate_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
 
-        if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to get server time",
-            ));
+    fn build_http_client(&mut self) -> Result<Client> {
+        let has_transport_options = self.timeout.is_some()
+            || self.connect_timeout.is_some()
+            || self.user_agent.is_some()
+            || self.proxy.is_some();
+
+        if let Some(client) = self.http_client.take() {
+            if has_transport_options {
+                return Err(PolyError::config(
+                    "HTTP client options cannot be combined with a custom reqwest::Client",
+                ));
+            }
+            return Ok(client);
         }
 
-        let time_text = response.text().await?;
-        let timestamp = time_text
-            .trim()
-            .parse::<u64>()
-            .map_err(|e| PolyError::parse(format!("Invalid timestamp format: {}", e), None))?;
+        let mut builder = Client::builder();
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent.as_str());
+        }
+        if let Some(proxy) = &self.proxy {
+            let proxy = reqwest::Proxy::all(proxy.as_str())
+                .map_err(|e| PolyError::config(format!("Invalid proxy URL: {}", e)))?;
+            builder = builder.proxy(proxy);
+        }
 
-        Ok(timestamp)
+        builder
+            .build()
+            .map_err(|e| PolyError::config(format!("Failed to build HTTP client: {}", e)))
     }
 
-    /// Get order book for a token
-    pub async fn get_order_book(&self, token_id: &str) -> Result<OrderBookSummary> {
-        let response = self
-            .http_client
-            .get(self.clob_url("book"))
-            .query(&[("token_id", token_id)])
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to get order book",
-            ));
+    /// Resolve the HTTP client, metadata cache, server clock and rate
+    /// limiter up front so that every client built from clones of this
+    /// builder shares them.
+    pub fn shared(mut self) -> Result<Self> {
+        if self.rate_limiter.is_none() {
+            self.rate_limits.validate()?;
+            self.rate_limiter = Some(Arc::new(RateLimiter::new(&self.rate_limits)));
         }
-
-        let order_book: OrderBookSummary = response.json().await?;
-        Ok(order_book)
+        self.http_client = Some(self.build_http_client()?);
+        self.timeout = None;
+        self.connect_timeout = None;
+        self.user_agent = None;
+        self.proxy = None;
+        self.metadata_cache.get_or_insert_with(Arc::default);
+        self.server_clock.get_or_insert_with(Arc::default);
+        Ok(self)
     }
 
-    /// Get midpoint for a token
-    pub async fn get_midpoint(&self, token_id: &str) -> Result<MidpointResponse> {
-        let response = self
-            .http_client
-            .get(self.clob_url("midpoint"))
-            .query(&[("token_id", token_id)])
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to get midpoint",
-            ));
-        }
+    /// HTTP client resolved by [`Self::shared`]
+    pub(crate) fn shared_http_client(&self) -> Client {
+        self.http_client.clone().unwrap_or_default()
+    }
 
-        let midpoint: MidpointResponse = response.json().await?;
-        Ok(midpoint)
+    /// Rate limiter resolved by [`Self::shared`]
+    pub(crate) fn shared_rate_limiter(&self) -> Arc<RateLimiter> {
+        self.rate_limiter.clone().unwrap_or_default()
     }
 
-    /// Get spread for a token
-    pub async fn get_spread(&self, token_id: &str) -> Result<SpreadResponse> {
-        let response = self
-            .http_client
+    /// Validate the configuration and build the client
+    pub fn build(mut self) -> Result<ClobClient> {
+        let rate_limiter = match self.rate_limiter.take() {
+            Some(limiter) => limiter,
+            None => {
+                self.rate_limits.validate()?;
+                Arc::new(RateLimiter::new(&self.rate_limits))
+            }
+        };
+        let http_client = self.build_http_client()?;
+
+        let signer = match (self.signer.take(), self.private_key.take()) {
+            (Some(_), Some(_)) => {
+                return Err(PolyError::config(
+                    "Provide either a signer or a private key, not both",
+                ));
+            }
+            (Some(signer), None) => Some(signer),
+            (None, Some(key)) => {
+                let signer = key
+                    .parse::<PrivateKeySigner>()
+                    .map_err(|e| PolyError::config(format!("Invalid private key: {}", e)))?;
+                Some(Arc::new(signer) as Arc<dyn PolySigner>)
+            }
+            (None, None) => None,
+        };
+
+        let funder = self
+            .funder
+            .as_deref()
+            .map(|funder| {
+                Address::from_str(funder).map_err(|err| {
+                    PolyError::validation(format!("Invalid funder address: {}", err))
+                })
+            })
+            .transpose()?;
+
+        let order_builder = match &signer {
+            Some(signer) => {
+                let sig_type = match (self.sig_type, funder) {
+                    (Some(sig_type), _) => sig_type,
+                    (None, None) => SigType::Eoa,
+                    (None, Some(_)) => {
+                        return Err(PolyError::config(
+                            "A funder requires an explicit signature type",
+                        ));
+                    }
+                };
+                let funder = match funder {
+                    Some(funder) => funder,
+                    None => derive_funder(signer.address(), sig_type, self.chain_id)?,
+                };
+                Some(crate::orders::OrderBuilder::new(
+                    signer.clone(),
+                    Some(sig_type),
+                    Some(funder),
+                )?)
+            }
+            None if funder.is_some() || self.sig_type.is_some() => {
+                return Err(PolyError::config(
+                    "Funder and signature type require a signer",
+                ));
+            }
+            None => None,
+        };
+
+        if self.api_creds.is_some() && signer.is_none() {
+            return Err(PolyError::config("API credentials require a signer"));
+        }
+
+        Ok(ClobClient {
+            http_client,
+            base_url: self.host,
+            gamma_base_url: self.gamma_base_url,
+            ws_base_url: self.ws_base_url,
+            rtds_base_url: self.rtds_base_url,
+            chain_id: self.chain_id,
+            signer,
+            api_creds: self.api_creds,
+            order_builder,
+            rate_limiter,
+            retry_config: self.retry_config,
+            metadata: self.metadata_cache.unwrap_or_default(),
+            clock: self.server_clock.unwrap_or_default(),
+        })
+    }
+}
+
+impl std::fmt::Debug for ClobClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClobClient")
+            .field("base_url", &self.base_url)
+            .field("chain_id", &self.chain_id)
+            .field("signer", &self.signer.as_ref().map(|s| s.address()))
+            .field("api_creds", &self.api_creds)
+            .field("funder", &self.funder())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ClobClient {
+    /// Create a new client
+    pub fn new(host: &str) -> Self {
+        Self::builder(host)
+            .build()
+            .expect("Default client configuration is valid")
+    }
+
+    /// Start a fallible [`ClobClientBuilder`] for the given host
+    pub fn builder(host: &str) -> ClobClientBuilder {
+        ClobClientBuilder::new(host)
+    }
+
+    fn encode_cursor(cursor: u64) -> String {
+        BASE64_ENGINE.encode(cursor.to_string())
+    }
+
+    fn decode_cursor(cursor: &str) -> Option<u64> {
+        BASE64_ENGINE
+            .decode(cursor)
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .and_then(|s| s.parse::<u64>().ok())
+    }
+
+    fn build_url(base: &str, path: &str) -> String {
+        let base = base.trim_end_matches('/');
+        let path = path.trim_start_matches('/');
+        if path.is_empty() {
+            base.to_string()
+        } else {
+            format!("{}/{}", base, path)
+        }
+    }
+
+    fn clob_url(&self, path: &str) -> String {
+        Self::build_url(&self.base_url, path)
+    }
+
+    fn gamma_url(&self, path: &str) -> String {
+        Self::build_url(&self.gamma_base_url, path)
+    }
+
+    /// Wait for rate limit capacity in the given endpoint family
+    async fn throttle(&self, family: EndpointFamily) {
+        let delay = self.rate_limiter.acquire(family).await;
+        if !delay.is_zero() {
+            tracing::debug!(?family, ?delay, "request delayed by rate limiter");
+        }
+    }
+
+    /// Send an idempotent request, retrying transient failures according to
+    /// the configured [`RetryConfig`].
+    ///
+    /// Callers throttle before building the request; each retry takes another
+    /// token from `family` so retries stay within the rate limit. Once retries
+    /// are exhausted the last response is returned so callers can report the
+    /// status as usual.
+    async fn send_idempotent(
+        &self,
+        family: Option<EndpointFamily>,
+        request: RequestBuilder,
+    ) -> Result<reqwest::Response> {
+        if self.retry_config.is_none() || request.try_clone().is_none() {
+            return Ok(request.send().await?);
+        }
+        self.send_rebuilt(family, || {
+            Ok(request.try_clone().expect("request was cloned above"))
+        })
+        .await
+    }
+
+    /// [`send_idempotent`](Self::send_idempotent) for requests that must be
+    /// rebuilt for every attempt, such as signed ones whose timestamp would
+    /// otherwise go stale.
+    ///
+    /// A `Retry-After` hint longer than the configured `max_delay` is not
+    /// waited out; the 429 is returned instead.
+    async fn send_rebuilt<F>(
+        &self,
+        family: Option<EndpointFamily>,
+        mut build: F,
+    ) -> Result<reqwest::Response>
+    where
+        F: FnMut() -> Result<RequestBuilder>,
+    {
+        let Some(config) = self.retry_config.as_ref() else {
+            return Ok(build()?.send().await?);
+        };
+
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let result = build()?.send().await.map_err(PolyError::from);
+            let retry_after = match &result {
+                Ok(response) => {
+                    let status = response.status();
+                    if status != reqwest::StatusCode::TOO_MANY_REQUESTS && !status.is_server_error()
+                    {
+                        return result;
+                    }
+                    parse_retry_after(response.headers())
+                }
+                Err(err) if err.is_retryable() => None,
+                Err(_) => return result,
+            };
+
+            if attempt >= config.max_attempts
+                || retry_after.is_some_and(|hint| hint > config.max_delay)
+            {
+                return result;
+            }
+
+            let delay = config
+                .backoff_delay(attempt - 1)
+                .max(retry_after.unwrap_or_default());
+            tracing::debug!(attempt, ?delay, "retrying request");
+            tokio::time::sleep(delay).await;
+            if let Some(family) = family {
+                self.throttle(family).await;
+            }
+        }
+    }
+
+    /// Delay metrics for an endpoint family, if it is rate limited
+    pub fn rate_limit_stats(&self, family: EndpointFamily) -> Option<RateLimitStats> {
+        self.rate_limiter.stats(family)
+    }
+
+    /// Per-token metadata cache consulted when building orders
+    pub fn metadata_cache(&self) -> &Arc<MarketMetadataCache> {
+        &self.metadata
+    }
+
+    /// Create a client with L1 headers (for authentication)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `private_key` is invalid; use [`ClobClient::builder`] to
+    /// handle that case as an error.
+    pub fn with_l1_headers(host: &str, private_key: &str, chain_id: u64) -> Self {
+        Self::builder(host)
+            .chain_id(chain_id)
+            .private_key(private_key)
+            .build()
+            .expect("Invalid private key")
+    }
+
+    /// Create a client with L2 headers (for API key authentication)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `private_key` is invalid; use [`ClobClient::builder`] to
+    /// handle that case as an error.
+    pub fn with_l2_headers(
+        host: &str,
+        private_key: &str,
+        chain_id: u64,
+        api_creds: ApiCreds,
+    ) -> Self {
+        Self::builder(host)
+            .chain_id(chain_id)
+            .private_key(private_key)
+            .api_creds(api_creds)
+            .build()
+            .expect("Invalid private key")
+    }
+
+    pub fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    /// Address of the signer, if one is configured
+    pub fn address(&self) -> Option<Address> {
+        self.signer.as_ref().map(|signer| signer.address())
+    }
+
+    /// Address that funds signed orders, if an order builder is configured
+    pub fn funder(&self) -> Option<Address> {
+        self.order_builder.as_ref().map(|builder| builder.funder())
+    }
+
+    pub fn api_creds(&self) -> Option<&ApiCreds> {
+        self.api_creds.as_ref()
+    }
+
+    /// Set API credentials
+    pub fn set_api_creds(&mut self, api_creds: ApiCreds) {
+        self.api_creds = Some(api_creds);
+    }
+
+    /// Override the funder/maker address used when creating signed orders.
+    ///
+    /// `sig_type` must match the funder: [`SigType::PolyProxy`] for the proxy
+    /// wallet of an email/magic account, [`SigType::PolyGnosisSafe`] for the
+    /// Safe of a browser-wallet account, or [`SigType::Eoa`] with the signer's
+    /// own address.
+    pub fn set_funder(&mut self, funder: &str, sig_type: SigType) -> Result<()> {
+        let address = Address::from_str(funder)
+            .map_err(|err| PolyError::validation(format!("Invalid funder address: {}", err)))?;
+
+        let order_builder = self
+            .order_builder
+            .as_mut()
+            .ok_or_else(|| PolyError::config("Order builder not initialized"))?;
+
+        order_builder.set_funder(address, sig_type)
+    }
+
+    /// Switch signature type, funding orders from the wallet derived for it
+    /// (the signer itself for [`SigType::Eoa`]). Returns the new funder.
+    pub fn set_signature_type(&mut self, sig_type: SigType) -> Result<Address> {
+        let signer = self
+            .signer
+            .as_ref()
+            .ok_or_else(|| PolyError::config("Signer not configured"))?;
+        let funder = derive_funder(signer.address(), sig_type, self.chain_id)?;
+
+        let order_builder = self
+            .order_builder
+            .as_mut()
+            .ok_or_else(|| PolyError::config("Order builder not initialized"))?;
+        order_builder.set_funder(funder, sig_type)?;
+        Ok(funder)
+    }
+
+    /// Override the Gamma API base URL
+    pub fn with_gamma_base(mut self, url: &str) -> Self {
+        self.gamma_base_url = url.to_string();
+        self
+    }
+
+    /// Override the WebSocket base URL
+    pub fn with_ws_base(mut self, url: &str) -> Self {
+        self.ws_base_url = url.to_string();
+        self
+    }
+
+    /// WebSocket base URL
+    pub fn ws_base_url(&self) -> &str {
+        &self.ws_base_url
+    }
+
+    /// Override the RTDS base URL
+    pub fn with_rtds_base(mut self, url: &str) -> Self {
+        self.rtds_base_url = url.to_string();
+        self
+    }
+
+    /// Test basic connectivity
+    pub async fn get_ok(&self) -> bool {
+        match self.http_client.get(self.clob_url("ok")).send().await {
+            Ok(response) => response.status().is_success(),
+            Err(_) => false,
+        }
+    }
+
+    /// Get server time
+    pub async fn get_server_time(&self) -> Result<u64> {
+        let request = self.http_client.get(self.clob_url("time"));
+        let response = self.send_idempotent(None, request).await?;
+
+        if !response.status().is_success() {
+            return Err(response_error(response, "Failed to get server time").await);
+        }
+
+        parse_server_time(&response.text().await?)
+    }
+
+    /// Estimated server clock used to stamp auth headers
+    pub fn server_clock(&self) -> &Arc<ServerClock> {
+        &self.clock
+    }
+
+    /// Sample the server clock a few times and adopt the offset from the
+    /// fastest round trip. Until this runs, headers use the local clock.
+    pub async fn sync_clock(&self) -> Result<ClockSample> {
+        sync_server_clock(&self.http_client, &self.clob_url("time"), &self.clock).await
+    }
+
+    /// Resync the server clock every `interval` in the background.
+    ///
+    /// Failed samples are logged and the previous offset is kept. Abort the
+    /// returned handle to stop syncing.
+    pub fn spawn_clock_sync(&self, interval: std::time::Duration) -> tokio::task::JoinHandle<()> {
+        let http_client = self.http_client.clone();
+        let url = self.clob_url("time");
+        let clock = self.clock.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(err) = sync_server_clock(&http_client, &url, &clock).await {
+                    tracing::warn!("clock sync failed: {}", err);
+                }
+            }
+        })
+    }
+
+    /// Expiration timestamp for a GTD order that should live for `lifetime`,
+    /// measured on the server clock.
+    ///
+    /// The exchange only honours expirations at least a minute in the
+    /// future, so that margin is added on top.
+    pub fn gtd_expiration(&self, lifetime: std::time::Duration) -> u64 {
+        self.clock.now_secs() + GTD_EXPIRATION_MARGIN_SECS + lifetime.as_secs()
+    }
+
+    /// Reject GTD expirations that the exchange would consider too close,
+    /// judged against the server clock rather than local time.
+    fn check_gtd_expiration(&self, expiration: u64) -> Result<()> {
+        let earliest = self.clock.now_secs() + GTD_EXPIRATION_MARGIN_SECS;
+        if expiration < earliest {
+            return Err(PolyError::validation(format!(
+                "GTD expiration {} is before {} on the server clock; use gtd_expiration()",
+                expiration, earliest
+            )));
+        }
+        Ok(())
+    }
+
+    /// Get order book for a token
+    pub async fn get_order_book(&self, token_id: &str) -> Result<OrderBookSummary> {
+        self.throttle(EndpointFamily::MarketData).await;
+
+        let request = self
+            .http_client
+            .get(self.clob_url("book"))
+            .query(&[("token_id", token_id)]);
+        let response = self
+            .send_idempotent(Some(EndpointFamily::MarketData), request)
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(response_error(response, "Failed to get order book").await);
+        }
+
+        let order_book: OrderBookSummary = response.json().await?;
+        Ok(order_book)
+    }
+
+    /// Get midpoint for a token
+    pub async fn get_midpoint(&self, token_id: &str) -> Result<MidpointResponse> {
+        self.throttle(EndpointFamily::MarketData).await;
+
+        let request = self
+            .http_client
+            .get(self.clob_url("midpoint"))
+            .query(&[("token_id", token_id)]);
+        let response = self
+            .send_idempotent(Some(EndpointFamily::MarketData), request)
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(response_error(response, "Failed to get midpoint").await);
+        }
+
+        let midpoint: MidpointResponse = response.json().await?;
+        Ok(midpoint)
+    }
+
+    /// Get spread for a token
+    pub async fn get_spread(&self, token_id: &str) -> Result<SpreadResponse> {
+        self.throttle(EndpointFamily::MarketData).await;
+
+        let request = self
+            .http_client
             .get(self.clob_url("spread"))
-            .query(&[("token_id", token_id)])
-            .send()
+            .query(&[("token_id", token_id)]);
+        let response = self
+            .send_idempotent(Some(EndpointFamily::MarketData), request)
             .await?;
 
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to get spread",
-            ));
+            return Err(response_error(response, "Failed to get spread").await);
         }
 
         let spread: SpreadResponse = response.json().await?;
         Ok(spread)
     }
 
+    /// Get the price history of a token as `(timestamp, price)` points,
+    /// oldest first
+    ///
+    /// ```no_run
+    /// # use polysqueeze::client::ClobClient;
+    /// # use polysqueeze::types::{PriceHistoryInterval, PriceHistoryParams};
+    /// # async fn run(client: ClobClient) -> polysqueeze::Result<()> {
+    /// let params = PriceHistoryParams {
+    ///     interval: Some(PriceHistoryInterval::OneDay),
+    ///     fidelity: Some(60),
+    ///     ..Default::default()
+    /// };
+    /// let history = client.get_prices_history("123", &params).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_prices_history(
+        &self,
+        token_id: &str,
+        params: &PriceHistoryParams,
+    ) -> Result<Vec<PricePoint>> {
+        params.validate()?;
+        self.throttle(EndpointFamily::MarketData).await;
+
+        let mut query = vec![("market", token_id.to_string())];
+        query.extend(params.to_query_params());
+        let request = self
+            .http_client
+            .get(self.clob_url("prices-history"))
+            .query(&query);
+        let response = self
+            .send_idempotent(Some(EndpointFamily::MarketData), request)
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(response_error(response, "Failed to get price history").await);
+        }
+
+        let mut history = response.json::<PriceHistoryResponse>().await?.history;
+        history.sort_by_key(|point| point.timestamp);
+        Ok(history)
+    }
+
     /// Get spreads for multiple tokens (batch)
     pub async fn get_spreads(
         &self,
         token_ids: &[String],
     ) -> Result<std::collections::HashMap<String, Decimal>> {
+        self.throttle(EndpointFamily::MarketData).await;
+
         let request_data: Vec<std::collections::HashMap<&str, String>> = token_ids
             .iter()
             .map(|id| {
@@ -412,10 +1253,7 @@ impl ClobClient {
             .await?;
 
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to get batch spreads",
-            ));
+            return Err(response_error(response, "Failed to get batch spreads").await);
         }
 
         response
@@ -426,18 +1264,18 @@ impl ClobClient {
 
     /// Get price for a token and side
     pub async fn get_price(&self, token_id: &str, side: Side) -> Result<PriceResponse> {
-        let response = self
+        self.throttle(EndpointFamily::MarketData).await;
+
+        let request = self
             .http_client
             .get(self.clob_url("price"))
-            .query(&[("token_id", token_id), ("side", side.as_str())])
-            .send()
+            .query(&[("token_id", token_id), ("side", side.as_str())]);
+        let response = self
+            .send_idempotent(Some(EndpointFamily::MarketData), request)
             .await?;
 
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to get price",
-            ));
+            return Err(response_error(response, "Failed to get price").await);
         }
 
         let price: PriceResponse = response.json().await?;
@@ -446,18 +1284,18 @@ impl ClobClient {
 
     /// Get tick size for a token
     pub async fn get_tick_size(&self, token_id: &str) -> Result<Decimal> {
-        let response = self
+        self.throttle(EndpointFamily::MarketData).await;
+
+        let request = self
             .http_client
             .get(self.clob_url("tick-size"))
-            .query(&[("token_id", token_id)])
-            .send()
+            .query(&[("token_id", token_id)]);
+        let response = self
+            .send_idempotent(Some(EndpointFamily::MarketData), request)
             .await?;
 
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to get tick size",
-            ));
+            return Err(response_error(response, "Failed to get tick size").await);
         }
 
         let tick_size_response: Value = response.json().await?;
@@ -471,6 +1309,7 @@ impl ClobClient {
             })
             .ok_or_else(|| PolyError::parse("Invalid tick size format", None))?;
 
+        self.metadata.set_tick_size(token_id, tick_size);
         Ok(tick_size)
     }
 
@@ -481,7 +1320,7 @@ impl ClobClient {
             .as_ref()
             .ok_or_else(|| PolyError::auth("Signer not set"))?;
 
-        let headers = create_l1_headers(signer, nonce)?;
+        let headers = create_l1_headers_at(signer, nonce, self.clock.now_secs()).await?;
         let req =
             self.create_request_with_headers(Method::POST, "/auth/api-key", headers.into_iter());
 
@@ -508,14 +1347,14 @@ impl ClobClient {
             .as_ref()
             .ok_or_else(|| PolyError::auth("Signer not set"))?;
 
-        let headers = create_l1_headers(signer, nonce)?;
+        let headers = create_l1_headers_at(signer, nonce, self.clock.now_secs()).await?;
         let req = self.create_request_with_headers(
             Method::GET,
             "/auth/derive-api-key",
             headers.into_iter(),
         );
 
-        let response = req.send().await?;
+        let response = self.send_idempotent(None, req).await?;
         if !response.status().is_success() {
             let status = response.status().as_u16();
             let body = response
@@ -550,23 +1389,10 @@ impl ClobClient {
             .as_ref()
             .ok_or_else(|| PolyError::config("API credentials not configured"))?;
 
-        let method = Method::GET;
         let endpoint = "/auth/api-keys";
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
-
         let response = self
-            .http_client
-            .request(method, self.clob_url(endpoint))
-            .headers(
-                headers
-                    .into_iter()
-                    .map(|(k, v)| (HeaderName::from_static(k), v.parse().unwrap()))
-                    .collect(),
-            )
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send_l2_get(None, signer.as_ref(), api_creds, endpoint, |r| r)
+            .await?;
 
         let api_keys_response: crate::types::ApiKeysResponse = response
             .json()
@@ -589,8 +1415,14 @@ impl ClobClient {
 
         let method = Method::DELETE;
         let endpoint = "/auth/api-key";
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
+        let headers = create_l2_headers_at::<_, Value>(
+            signer,
+            api_creds,
+            method.as_str(),
+            endpoint,
+            None,
+            self.clock.now_secs(),
+        )?;
 
         let response = self
             .http_client
@@ -611,6 +1443,34 @@ impl ClobClient {
             .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
     }
 
+    /// Send a signed L2 `GET`, signing every attempt afresh so retries never
+    /// replay a stale `poly_timestamp`. `query` adds the query string.
+    async fn send_l2_get(
+        &self,
+        family: Option<EndpointFamily>,
+        signer: &dyn PolySigner,
+        api_creds: &ApiCreds,
+        endpoint: &str,
+        query: impl Fn(RequestBuilder) -> RequestBuilder,
+    ) -> Result<reqwest::Response> {
+        self.send_rebuilt(family, || {
+            let headers = create_l2_headers_at::<_, Value>(
+                signer,
+                api_creds,
+                "GET",
+                endpoint,
+                None,
+                self.clock.now_secs(),
+            )?;
+            Ok(query(self.create_request_with_headers(
+                Method::GET,
+                endpoint,
+                headers.into_iter(),
+            )))
+        })
+        .await
+    }
+
     /// Helper to create request with headers
     fn create_request_with_headers(
         &self,
@@ -624,18 +1484,18 @@ impl ClobClient {
 
     /// Get neg risk for a token
     pub async fn get_neg_risk(&self, token_id: &str) -> Result<bool> {
-        let response = self
+        self.throttle(EndpointFamily::MarketData).await;
+
+        let request = self
             .http_client
             .get(self.clob_url("neg-risk"))
-            .query(&[("token_id", token_id)])
-            .send()
+            .query(&[("token_id", token_id)]);
+        let response = self
+            .send_idempotent(Some(EndpointFamily::MarketData), request)
             .await?;
 
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to get neg risk",
-            ));
+            return Err(response_error(response, "Failed to get neg risk").await);
         }
 
         let neg_risk_response: Value = response.json().await?;
@@ -643,16 +1503,70 @@ impl ClobClient {
             .as_bool()
             .ok_or_else(|| PolyError::parse("Invalid neg risk format", None))?;
 
+        self.metadata.set_neg_risk(token_id, neg_risk);
         Ok(neg_risk)
     }
 
+    /// Get the base fee rate (in basis points) charged on a token
+    pub async fn get_fee_rate_bps(&self, token_id: &str) -> Result<u32> {
+        self.throttle(EndpointFamily::MarketData).await;
+
+        let request = self
+            .http_client
+            .get(self.clob_url("fee-rate"))
+            .query(&[("token_id", token_id)]);
+        let response = self
+            .send_idempotent(Some(EndpointFamily::MarketData), request)
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(response_error(response, "Failed to get fee rate").await);
+        }
+
+        let fee_rate_response: Value = response.json().await?;
+        let fee_rate_bps = fee_rate_response["base_fee"]
+            .as_u64()
+            .or_else(|| {
+                fee_rate_response["base_fee"]
+                    .as_str()
+                    .and_then(|s| s.parse().ok())
+            })
+            .and_then(|bps| u32::try_from(bps).ok())
+            .ok_or_else(|| PolyError::parse("Invalid fee rate format", None))?;
+
+        self.metadata.set_fee_rate_bps(token_id, fee_rate_bps);
+        Ok(fee_rate_bps)
+    }
+
+    /// Fetch tick size, neg risk and fee rate for each token so later orders
+    /// can be signed without any HTTP round trip
+    pub async fn prewarm_market_metadata(&self, token_ids: &[&str]) -> Result<()> {
+        futures::future::try_join_all(token_ids.iter().map(|token_id| async move {
+            futures::try_join!(
+                self.get_tick_size(token_id),
+                self.get_neg_risk(token_id),
+                self.get_fee_rate_bps(token_id),
+            )
+        }))
+        .await?;
+        Ok(())
+    }
+
+    /// Forget cached metadata for a token; the next order refetches it
+    pub fn invalidate_market_metadata(&self, token_id: &str) {
+        self.metadata.invalidate(token_id);
+    }
+
     /// Resolve tick size for an order
     async fn resolve_tick_size(
         &self,
         token_id: &str,
         tick_size: Option<Decimal>,
     ) -> Result<Decimal> {
-        let min_tick_size = self.get_tick_size(token_id).await?;
+        let min_tick_size = match self.metadata.tick_size(token_id) {
+            Some(cached) => cached,
+            None => self.get_tick_size(token_id).await?,
+        };
 
         match tick_size {
             None => Ok(min_tick_size),
@@ -681,10 +1595,12 @@ impl ClobClient {
         };
 
         let tick_size = self.resolve_tick_size(token_id, tick_size).await?;
-        let neg_risk = match neg_risk {
+        let neg_risk = match neg_risk.or_else(|| self.metadata.neg_risk(token_id)) {
             Some(nr) => nr,
             None => self.get_neg_risk(token_id).await?,
         };
+        // The fee rate is only known once prewarmed; it is never fetched here
+        let fee_rate_bps = fee_rate_bps.or_else(|| self.metadata.fee_rate_bps(token_id));
 
         Ok(OrderOptions {
             tick_size: Some(tick_size),
@@ -718,7 +1634,13 @@ impl ClobClient {
             .await?;
 
         let expiration = expiration.unwrap_or(0);
-        let extras = extras.unwrap_or_default();
+        if expiration > 0 {
+            self.check_gtd_expiration(expiration)?;
+        }
+        let extras = extras.unwrap_or_else(|| crate::types::ExtraOrderArgs {
+            fee_rate_bps: create_order_options.fee_rate_bps.unwrap_or_default(),
+            ..Default::default()
+        });
 
         if !self.is_price_in_range(
             order_args.price,
@@ -734,6 +1656,7 @@ impl ClobClient {
             &extras,
             &create_order_options,
         )
+        .await
     }
 
     /// Calculate market price from order book
@@ -742,6 +1665,7 @@ impl ClobClient {
         token_id: &str,
         side: Side,
         amount: Decimal,
+        order_type: OrderType,
     ) -> Result<Decimal> {
         let book = self.get_order_book(token_id).await?;
         let order_builder = self
@@ -749,27 +1673,23 @@ impl ClobClient {
             .as_ref()
             .ok_or_else(|| PolyError::auth("Order builder not initialized"))?;
 
-        // Convert OrderSummary to BookLevel
-        let levels: Vec<crate::types::BookLevel> = match side {
-            Side::BUY => book
-                .asks
-                .into_iter()
-                .map(|s| crate::types::BookLevel {
-                    price: s.price,
-                    size: s.size,
-                })
-                .collect(),
-            Side::SELL => book
-                .bids
-                .into_iter()
-                .map(|s| crate::types::BookLevel {
-                    price: s.price,
-                    size: s.size,
-                })
-                .collect(),
-        };
+        // Buys walk the asks and sells walk the bids, best price first
+        let mut levels: Vec<crate::types::BookLevel> = match side {
+            Side::BUY => book.asks,
+            Side::SELL => book.bids,
+        }
+        .into_iter()
+        .map(|s| crate::types::BookLevel {
+            price: s.price,
+            size: s.size,
+        })
+        .collect();
+        match side {
+            Side::BUY => levels.sort_by_key(|level| level.price),
+            Side::SELL => levels.sort_by_key(|level| std::cmp::Reverse(level.price)),
+        }
 
-        order_builder.calculate_market_price(&levels, amount)
+        order_builder.calculate_market_price(&levels, amount, side, order_type)
     }
 
     /// Create a market order
@@ -779,6 +1699,12 @@ impl ClobClient {
         extras: Option<crate::types::ExtraOrderArgs>,
         options: Option<&OrderOptions>,
     ) -> Result<SignedOrderRequest> {
+        if !matches!(order_args.order_type, OrderType::FOK | OrderType::FAK) {
+            return Err(PolyError::validation(format!(
+                "Market orders must be FOK or FAK, got {}",
+                order_args.order_type.as_str()
+            )));
+        }
         let order_builder = self
             .order_builder
             .as_ref()
@@ -788,9 +1714,17 @@ impl ClobClient {
             .get_filled_order_options(&order_args.token_id, options)
             .await?;
 
-        let extras = extras.unwrap_or_default();
+        let extras = extras.unwrap_or_else(|| crate::types::ExtraOrderArgs {
+            fee_rate_bps: create_order_options.fee_rate_bps.unwrap_or_default(),
+            ..Default::default()
+        });
         let price = self
-            .calculate_market_price(&order_args.token_id, Side::BUY, order_args.amount)
+            .calculate_market_price(
+                &order_args.token_id,
+                order_args.side,
+                order_args.amount,
+                order_args.order_type,
+            )
             .await?;
 
         if !self.is_price_in_range(
@@ -807,6 +1741,50 @@ impl ClobClient {
             &extras,
             &create_order_options,
         )
+        .await
+    }
+
+    /// Verify a signed order offline and return its order id (the exchange
+    /// order hash). The neg-risk flag comes from the metadata cache, falling
+    /// back to `/neg-risk`.
+    pub async fn verify_order(&self, order: &SignedOrderRequest) -> Result<String> {
+        let neg_risk = match self.metadata.neg_risk(&order.token_id) {
+            Some(neg_risk) => neg_risk,
+            None => self.get_neg_risk(&order.token_id).await?,
+        };
+        let hash = order.verify(self.chain_id, neg_risk)?;
+        Ok(hash.to_string())
+    }
+
+    /// Submit an order signed offline (see
+    /// [`OrderBuilder::sign_order_offline`](crate::orders::OrderBuilder::sign_order_offline)).
+    ///
+    /// The order must target one of this client's exchanges and verify
+    /// against it before posting, so a submitter holding only an
+    /// [`AddressOnlySigner`](crate::auth::AddressOnlySigner) still rejects
+    /// corrupted or mis-targeted orders.
+    pub async fn post_offline_order(
+        &self,
+        order: crate::types::OfflineOrder,
+    ) -> Result<crate::types::PostOrderResponse> {
+        if order.chain_id != self.chain_id {
+            return Err(PolyError::validation(format!(
+                "Order was signed for chain {}, client is on chain {}",
+                order.chain_id, self.chain_id
+            )));
+        }
+        let known = [false, true]
+            .into_iter()
+            .filter_map(|neg_risk| crate::orders::exchange_address(self.chain_id, neg_risk).ok())
+            .any(|exchange| exchange == order.exchange);
+        if !known {
+            return Err(PolyError::validation(format!(
+                "Order targets exchange {}, which is not an exchange on chain {}",
+                order.exchange, self.chain_id
+            )));
+        }
+        order.order.verify_for(order.chain_id, order.exchange)?;
+        self.post_order(order.order, order.order_type).await
     }
 
     /// Post an order to the exchange
@@ -814,7 +1792,15 @@ impl ClobClient {
         &self,
         order: SignedOrderRequest,
         order_type: OrderType,
-    ) -> Result<Value> {
+    ) -> Result<PostOrderResponse> {
+        if order_type == OrderType::GTD {
+            let expiration = order.expiration.parse::<u64>().map_err(|_| {
+                PolyError::validation(format!("Invalid expiration: {:?}", order.expiration))
+            })?;
+            self.check_gtd_expiration(expiration)?;
+        }
+        self.throttle(EndpointFamily::OrderPosting).await;
+
         let signer = self
             .signer
             .as_ref()
@@ -826,26 +1812,28 @@ impl ClobClient {
 
         let body = PostOrder::new(order, api_creds.api_key.clone(), order_type);
 
-        let headers = create_l2_headers(signer, api_creds, "POST", "/order", Some(&body))?;
-        if env::var("POLY_LOG_REQUEST").is_ok() {
-            if let Ok(body_text) = serde_json::to_string(&body) {
-                println!("rust request url    : {}", self.clob_url("/order"));
-                println!("rust request method : POST");
-                println!("rust request headers: {:?}", headers);
-                println!("rust request body   : {}", body_text);
-            }
-        }
+        let headers = create_l2_headers_at(
+            signer,
+            api_creds,
+            "POST",
+            "/order",
+            Some(&body),
+            self.clock.now_secs(),
+        )?;
+        tracing::debug!(
+            url = %self.clob_url("/order"),
+            ?headers,
+            body = %redact_order_owner(&body),
+            "posting orders"
+        );
         let req = self.create_request_with_headers(Method::POST, "/order", headers.into_iter());
 
         let response = req.json(&body).send().await?;
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                format!("Failed to post order {}", response.text().await.unwrap()),
-            ));
+            return Err(response_error(response, "Failed to post order").await);
         }
 
-        Ok(response.json::<Value>().await?)
+        Ok(response.json::<PostOrderResponse>().await?)
     }
 
     /// Post multiple orders in a single batch request
@@ -859,7 +1847,9 @@ impl ClobClient {
         &self,
         orders: Vec<SignedOrderRequest>,
         order_type: OrderType,
-    ) -> Result<Vec<Value>> {
+    ) -> Result<Vec<PostOrderResponse>> {
+        self.throttle(EndpointFamily::OrderPosting).await;
+
         let signer = self
             .signer
             .as_ref()
@@ -871,41 +1861,129 @@ impl ClobClient {
 
         let batch: Vec<PostOrder> = orders
             .into_iter()
-            .map(|order| PostOrder::new(order, api_creds.api_key.clone(), order_type.clone()))
+            .map(|order| PostOrder::new(order, api_creds.api_key.clone(), order_type))
             .collect();
 
-        let headers = create_l2_headers(signer, api_creds, "POST", "/orders", Some(&batch))?;
+        let headers = create_l2_headers_at(
+            signer,
+            api_creds,
+            "POST",
+            "/orders",
+            Some(&batch),
+            self.clock.now_secs(),
+        )?;
 
-        if env::var("POLY_LOG_REQUEST").is_ok() {
-            if let Ok(body_text) = serde_json::to_string(&batch) {
-                println!("rust request url    : {}", self.clob_url("/orders"));
-                println!("rust request method : POST");
-                println!("rust request headers: {:?}", headers);
-                println!("rust request body   : {}", body_text);
-            }
-        }
+        tracing::debug!(
+            url = %self.clob_url("/orders"),
+            ?headers,
+            body = %redact_order_owner(&batch),
+            "posting orders"
+        );
 
         let req = self.create_request_with_headers(Method::POST, "/orders", headers.into_iter());
 
         let response = req.json(&batch).send().await?;
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                format!("Failed to post batch orders: {}", response.text().await.unwrap()),
-            ));
+            return Err(response_error(response, "Failed to post batch orders").await);
+        }
+
+        Ok(response.json::<Vec<PostOrderResponse>>().await?)
+    }
+
+    /// Submit any number of orders, reporting a result for each one.
+    ///
+    /// Every order is validated locally first; invalid or duplicated orders
+    /// are never sent. The rest are split into chunks of at most
+    /// `MAX_BATCH_ORDERS` (the exchange's per-request limit) and the chunks
+    /// are posted concurrently. Rejections come back as typed
+    /// [`PolyError::Order`] errors, and a chunk that fails as a whole
+    /// reports its error for every order it contained.
+    ///
+    /// Only a missing signer or API credentials fail the call itself.
+    pub async fn post_orders_batch(
+        &self,
+        orders: Vec<SignedOrderRequest>,
+        order_type: OrderType,
+    ) -> Result<BatchPostResult> {
+        if self.signer.is_none() {
+            return Err(PolyError::auth("Signer not set"));
+        }
+        if self.api_creds.is_none() {
+            return Err(PolyError::auth("API credentials not set"));
+        }
+
+        let now = self.clock.now_secs();
+        let mut results: Vec<Option<Result<PostOrderResponse>>> = vec![None; orders.len()];
+        let mut signatures = std::collections::HashSet::new();
+        let mut pending = Vec::with_capacity(orders.len());
+        for (index, order) in orders.into_iter().enumerate() {
+            let checked = order.validate(order_type, now).and_then(|_| {
+                if signatures.insert(order.signature.clone()) {
+                    Ok(())
+                } else {
+                    Err(PolyError::order(
+                        "Order appears more than once in the batch",
+                        crate::errors::OrderErrorKind::DuplicateOrder,
+                    ))
+                }
+            });
+            match checked {
+                Ok(()) => pending.push((index, order)),
+                Err(err) => results[index] = Some(Err(err)),
+            }
+        }
+
+        let chunks = pending.chunks(MAX_BATCH_ORDERS).map(|chunk| async move {
+            let (indices, orders): (Vec<usize>, Vec<SignedOrderRequest>) =
+                chunk.iter().cloned().unzip();
+            (indices, self.post_orders(orders, order_type).await)
+        });
+        for (indices, outcome) in futures::future::join_all(chunks).await {
+            match outcome {
+                Ok(responses) if responses.len() == indices.len() => {
+                    for (index, response) in indices.into_iter().zip(responses) {
+                        results[index] = Some(response.into_result());
+                    }
+                }
+                Ok(responses) => {
+                    let err = PolyError::parse(
+                        format!(
+                            "Batch response has {} entries for {} orders",
+                            responses.len(),
+                            indices.len()
+                        ),
+                        None,
+                    );
+                    for index in indices {
+                        results[index] = Some(Err(err.clone()));
+                    }
+                }
+                Err(err) => {
+                    for index in indices {
+                        results[index] = Some(Err(err.clone()));
+                    }
+                }
+            }
         }
 
-        Ok(response.json::<Vec<Value>>().await?)
+        Ok(BatchPostResult {
+            results: results
+                .into_iter()
+                .map(|result| result.expect("every order has a result"))
+                .collect(),
+        })
     }
 
     /// Create and post an order in one call
-    pub async fn create_and_post_order(&self, order_args: &OrderArgs) -> Result<Value> {
+    pub async fn create_and_post_order(&self, order_args: &OrderArgs) -> Result<PostOrderResponse> {
         let order = self.create_order(order_args, None, None, None).await?;
         self.post_order(order, OrderType::GTC).await
     }
 
     /// Cancel an order
-    pub async fn cancel(&self, order_id: &str) -> Result<Value> {
+    pub async fn cancel(&self, order_id: &str) -> Result<CancelOrdersResponse> {
+        self.throttle(EndpointFamily::Cancels).await;
+
         let signer = self
             .signer
             .as_ref()
@@ -917,22 +1995,89 @@ impl ClobClient {
 
         let body = std::collections::HashMap::from([("orderID", order_id)]);
 
-        let headers = create_l2_headers(signer, api_creds, "DELETE", "/order", Some(&body))?;
+        let headers = create_l2_headers_at(
+            signer,
+            api_creds,
+            "DELETE",
+            "/order",
+            Some(&body),
+            self.clock.now_secs(),
+        )?;
         let req = self.create_request_with_headers(Method::DELETE, "/order", headers.into_iter());
 
         let response = req.json(&body).send().await?;
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to cancel order",
-            ));
+            return Err(response_error(response, "Failed to cancel order").await);
+        }
+
+        Ok(response.json::<CancelOrdersResponse>().await?)
+    }
+
+    /// Replace a resting order with `new_order`, in the order given by `mode`.
+    ///
+    /// The second step only runs when the first succeeded, so a failure never
+    /// leaves the book worse off than the chosen mode allows. Check the
+    /// returned [`ReplaceOutcome`] to see exactly which step failed.
+    pub async fn replace_order(
+        &self,
+        old_order_id: &str,
+        new_order: SignedOrderRequest,
+        order_type: OrderType,
+        mode: ReplaceMode,
+    ) -> Result<ReplaceOutcome> {
+        if self.signer.is_none() {
+            return Err(PolyError::auth("Signer not set"));
         }
+        if self.api_creds.is_none() {
+            return Err(PolyError::auth("API credentials not set"));
+        }
+
+        let cancel_old = || async {
+            self.cancel(old_order_id)
+                .await
+                .and_then(|response| response.check_canceled(old_order_id))
+        };
+        let post_new = || async {
+            self.post_order(new_order.clone(), order_type)
+                .await
+                .and_then(PostOrderResponse::into_result)
+        };
 
-        Ok(response.json::<Value>().await?)
+        let outcome = match mode {
+            ReplaceMode::CancelThenPost => match cancel_old().await {
+                Err(error) => ReplaceOutcome::CancelFailed {
+                    error,
+                    new_order: None,
+                },
+                Ok(()) => match post_new().await {
+                    Ok(new_order) => ReplaceOutcome::Replaced { new_order },
+                    Err(error) => ReplaceOutcome::PostFailed {
+                        error,
+                        old_cancelled: true,
+                    },
+                },
+            },
+            ReplaceMode::PostThenCancel => match post_new().await {
+                Err(error) => ReplaceOutcome::PostFailed {
+                    error,
+                    old_cancelled: false,
+                },
+                Ok(new_order) => match cancel_old().await {
+                    Ok(()) => ReplaceOutcome::Replaced { new_order },
+                    Err(error) => ReplaceOutcome::CancelFailed {
+                        error,
+                        new_order: Some(new_order),
+                    },
+                },
+            },
+        };
+        Ok(outcome)
     }
 
     /// Cancel multiple orders
-    pub async fn cancel_orders(&self, order_ids: &[String]) -> Result<Value> {
+    pub async fn cancel_orders(&self, order_ids: &[String]) -> Result<CancelOrdersResponse> {
+        self.throttle(EndpointFamily::Cancels).await;
+
         let signer = self
             .signer
             .as_ref()
@@ -942,22 +2087,28 @@ impl ClobClient {
             .as_ref()
             .ok_or_else(|| PolyError::auth("API credentials not set"))?;
 
-        let headers = create_l2_headers(signer, api_creds, "DELETE", "/orders", Some(order_ids))?;
+        let headers = create_l2_headers_at(
+            signer,
+            api_creds,
+            "DELETE",
+            "/orders",
+            Some(order_ids),
+            self.clock.now_secs(),
+        )?;
         let req = self.create_request_with_headers(Method::DELETE, "/orders", headers.into_iter());
 
         let response = req.json(order_ids).send().await?;
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to cancel orders",
-            ));
+            return Err(response_error(response, "Failed to cancel orders").await);
         }
 
-        Ok(response.json::<Value>().await?)
+        Ok(response.json::<CancelOrdersResponse>().await?)
     }
 
     /// Cancel all orders
-    pub async fn cancel_all(&self) -> Result<Value> {
+    pub async fn cancel_all(&self) -> Result<CancelOrdersResponse> {
+        self.throttle(EndpointFamily::Cancels).await;
+
         let signer = self
             .signer
             .as_ref()
@@ -967,19 +2118,67 @@ impl ClobClient {
             .as_ref()
             .ok_or_else(|| PolyError::auth("API credentials not set"))?;
 
-        let headers = create_l2_headers::<Value>(signer, api_creds, "DELETE", "/cancel-all", None)?;
+        let headers = create_l2_headers_at::<_, Value>(
+            signer,
+            api_creds,
+            "DELETE",
+            "/cancel-all",
+            None,
+            self.clock.now_secs(),
+        )?;
         let req =
             self.create_request_with_headers(Method::DELETE, "/cancel-all", headers.into_iter());
 
         let response = req.send().await?;
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to cancel all orders",
-            ));
+            return Err(response_error(response, "Failed to cancel all orders").await);
+        }
+
+        Ok(response.json::<CancelOrdersResponse>().await?)
+    }
+
+    /// Send a server-side heartbeat.
+    ///
+    /// Once heartbeats have started, the exchange cancels every open order of
+    /// the account if they stop arriving. Pass the id returned by the
+    /// previous call to keep the same heartbeat session alive.
+    pub async fn post_heartbeat(&self, heartbeat_id: Option<&str>) -> Result<String> {
+        self.throttle(EndpointFamily::Heartbeats).await;
+
+        let signer = self
+            .signer
+            .as_ref()
+            .ok_or_else(|| PolyError::auth("Signer not set"))?;
+        let api_creds = self
+            .api_creds
+            .as_ref()
+            .ok_or_else(|| PolyError::auth("API credentials not set"))?;
+
+        let endpoint = "/v1/heartbeats";
+        let body = serde_json::json!({ "heartbeat_id": heartbeat_id });
+        let headers = create_l2_headers_at(
+            signer,
+            api_creds,
+            "POST",
+            endpoint,
+            Some(&body),
+            self.clock.now_secs(),
+        )?;
+        let req = self
+            .create_request_with_headers(Method::POST, endpoint, headers.into_iter())
+            .json(&body);
+
+        let response = req.send().await?;
+        if !response.status().is_success() {
+            return Err(response_error(response, "Failed to send heartbeat").await);
         }
 
-        Ok(response.json::<Value>().await?)
+        let heartbeat: Value = response.json().await?;
+        heartbeat["heartbeat_id"]
+            .as_str()
+            .or(heartbeat_id)
+            .map(str::to_string)
+            .ok_or_else(|| PolyError::parse("Heartbeat response missing heartbeat_id", None))
     }
 
     /// Get open orders with optional filtering
@@ -990,73 +2189,64 @@ impl ClobClient {
     /// - Market ID (all orders for a specific market)
     ///
     /// The response includes order status, fill information, and timestamps.
+    /// Use [`ClobClient::get_orders_stream`] to process large result sets
+    /// page by page.
     pub async fn get_orders(
         &self,
         params: Option<&crate::types::OpenOrderParams>,
         next_cursor: Option<&str>,
     ) -> Result<Vec<crate::types::OpenOrder>> {
-        let signer = self
-            .signer
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("Signer not set"))?;
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("API credentials not set"))?;
-
-        let method = Method::GET;
-        let endpoint = "/data/orders";
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
+        let mut output = Vec::new();
+        let mut pages = std::pin::pin!(self.get_orders_stream(params.cloned(), next_cursor));
+        while let Some(page) = pages.try_next().await? {
+            output.extend(page.data);
+        }
+        Ok(output)
+    }
 
+    /// Fetch a single page of open orders starting at `cursor`
+    pub async fn get_orders_page(
+        &self,
+        params: Option<&crate::types::OpenOrderParams>,
+        cursor: &str,
+    ) -> Result<Page<crate::types::OpenOrder>> {
         let query_params = match params {
             None => Vec::new(),
             Some(p) => p.to_query_params(),
         };
 
-        let mut next_cursor = next_cursor.unwrap_or("MA==").to_string(); // INITIAL_CURSOR
-        let mut output = Vec::new();
-
-        while next_cursor != "LTE=" {
-            // END_CURSOR
-            let req = self
-                .http_client
-                .request(method.clone(), self.clob_url(endpoint))
-                .query(&query_params)
-                .query(&[("next_cursor", &next_cursor)]);
-
-            let r = headers
-                .clone()
-                .into_iter()
-                .fold(req, |r, (k, v)| r.header(HeaderName::from_static(k), v));
-
-            let resp = r
-                .send()
-                .await
-                .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?
-                .json::<Value>()
-                .await
-                .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))?;
-
-            let new_cursor = resp["next_cursor"]
-                .as_str()
-                .ok_or_else(|| PolyError::parse("Failed to parse next cursor".to_string(), None))?
-                .to_owned();
-
-            next_cursor = new_cursor;
+        let (data, next_cursor) = self
+            .get_l2_page("/data/orders", &query_params, cursor)
+            .await?;
+        let orders = serde_json::from_value::<Vec<crate::types::OpenOrder>>(data).map_err(|e| {
+            PolyError::parse(
+                format!("Failed to parse data from order response: {}", e),
+                None,
+            )
+        })?;
 
-            let results = resp["data"].clone();
-            let orders =
-                serde_json::from_value::<Vec<crate::types::OpenOrder>>(results).map_err(|e| {
-                    PolyError::parse(
-                        format!("Failed to parse data from order response: {}", e),
-                        None,
-                    )
-                })?;
-            output.extend(orders);
-        }
+        Ok(Page {
+            data: orders,
+            next_cursor,
+        })
+    }
 
-        Ok(output)
+    /// Stream open orders page by page.
+    ///
+    /// Pass a previously saved [`Page::next_cursor`] to resume where an
+    /// earlier stream stopped.
+    pub fn get_orders_stream(
+        &self,
+        params: Option<crate::types::OpenOrderParams>,
+        next_cursor: Option<&str>,
+    ) -> impl Stream<Item = Result<Page<crate::types::OpenOrder>>> + '_ {
+        paginate(
+            next_cursor.unwrap_or(INITIAL_CURSOR).to_string(),
+            move |cursor| {
+                let params = params.clone();
+                async move { self.get_orders_page(params.as_ref(), &cursor).await }
+            },
+        )
     }
 
     /// Get trade history with optional filtering
@@ -1068,79 +2258,126 @@ impl ClobClient {
     /// - Asset/Token ID (trades for a specific token)
     /// - Time range (before/after timestamps)
     ///
-    /// Trades are returned in reverse chronological order (newest first).
+    /// Trades are returned in reverse chronological order (newest first), one
+    /// JSON array per page. Use [`ClobClient::get_trades_stream`] to process
+    /// large histories incrementally.
     pub async fn get_trades(
         &self,
         trade_params: Option<&crate::types::TradeParams>,
         next_cursor: Option<&str>,
     ) -> Result<Vec<Value>> {
-        let signer = self
-            .signer
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("Signer not set"))?;
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| PolyError::auth("API credentials not set"))?;
-
-        let method = Method::GET;
-        let endpoint = "/data/trades";
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
+        let mut output = Vec::new();
+        let mut pages = std::pin::pin!(self.get_trades_stream(trade_params.cloned(), next_cursor));
+        while let Some(page) = pages.try_next().await? {
+            output.push(Value::Array(page.data));
+        }
+        Ok(output)
+    }
 
+    /// Fetch a single page of trades starting at `cursor`
+    pub async fn get_trades_page(
+        &self,
+        trade_params: Option<&crate::types::TradeParams>,
+        cursor: &str,
+    ) -> Result<Page<Value>> {
         let query_params = match trade_params {
             None => Vec::new(),
             Some(p) => p.to_query_params(),
         };
 
-        let mut next_cursor = next_cursor.unwrap_or("MA==").to_string(); // INITIAL_CURSOR
-        let mut output = Vec::new();
-
-        while next_cursor != "LTE=" {
-            // END_CURSOR
-            let req = self
-                .http_client
-                .request(method.clone(), self.clob_url(endpoint))
-                .query(&query_params)
-                .query(&[("next_cursor", &next_cursor)]);
-
-            let r = headers
-                .clone()
-                .into_iter()
-                .fold(req, |r, (k, v)| r.header(HeaderName::from_static(k), v));
-
-            let resp = r
-                .send()
-                .await
-                .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?
-                .json::<Value>()
-                .await
-                .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))?;
-
-            let new_cursor = resp["next_cursor"]
-                .as_str()
-                .ok_or_else(|| PolyError::parse("Failed to parse next cursor".to_string(), None))?
-                .to_owned();
-
-            next_cursor = new_cursor;
-
-            let results = resp["data"].clone();
-            output.push(results);
-        }
+        let (data, next_cursor) = self
+            .get_l2_page("/data/trades", &query_params, cursor)
+            .await?;
+        let trades = match data {
+            Value::Array(trades) => trades,
+            Value::Null => Vec::new(),
+            other => vec![other],
+        };
 
-        Ok(output)
+        Ok(Page {
+            data: trades,
+            next_cursor,
+        })
     }
 
-    /// Get balance and allowance information for all assets
-    ///
-    /// This returns the current balance and allowance for each asset in your account.
-    /// Balance is how much you own, allowance is how much the exchange can spend on your behalf.
+    /// Stream trades page by page.
     ///
-    /// You need both balance and allowance to place orders - the exchange needs permission
-    /// to move your tokens when orders are filled.
-    pub async fn get_balance_allowance(
+    /// Pass a previously saved [`Page::next_cursor`] to resume where an
+    /// earlier stream stopped.
+    pub fn get_trades_stream(
         &self,
-        params: Option<crate::types::BalanceAllowanceParams>,
+        trade_params: Option<crate::types::TradeParams>,
+        next_cursor: Option<&str>,
+    ) -> impl Stream<Item = Result<Page<Value>>> + '_ {
+        paginate(
+            next_cursor.unwrap_or(INITIAL_CURSOR).to_string(),
+            move |cursor| {
+                let trade_params = trade_params.clone();
+                async move { self.get_trades_page(trade_params.as_ref(), &cursor).await }
+            },
+        )
+    }
+
+    /// Fetch one page of a cursor-paginated L2 endpoint, returning the raw
+    /// `data` payload and the next cursor (`None` at the end). Each page is
+    /// throttled under [`EndpointFamily::AccountData`].
+    async fn get_l2_page<K, V>(
+        &self,
+        endpoint: &str,
+        query_params: &[(K, V)],
+        cursor: &str,
+    ) -> Result<(Value, Option<String>)>
+    where
+        K: serde::Serialize,
+        V: serde::Serialize,
+    {
+        self.throttle(EndpointFamily::AccountData).await;
+
+        let signer = self
+            .signer
+            .as_ref()
+            .ok_or_else(|| PolyError::auth("Signer not set"))?;
+        let api_creds = self
+            .api_creds
+            .as_ref()
+            .ok_or_else(|| PolyError::auth("API credentials not set"))?;
+
+        let response = self
+            .send_l2_get(
+                Some(EndpointFamily::AccountData),
+                signer.as_ref(),
+                api_creds,
+                endpoint,
+                |r| r.query(query_params).query(&[("next_cursor", cursor)]),
+            )
+            .await?;
+        if !response.status().is_success() {
+            return Err(response_error(response, &format!("Failed to get {}", endpoint)).await);
+        }
+
+        let mut resp = response
+            .json::<Value>()
+            .await
+            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))?;
+
+        let next_cursor = resp["next_cursor"]
+            .as_str()
+            .ok_or_else(|| PolyError::parse("Failed to parse next cursor".to_string(), None))?;
+
+        let next_cursor = (next_cursor != END_CURSOR).then(|| next_cursor.to_owned());
+        Ok((resp["data"].take(), next_cursor))
+    }
+
+    /// Get balance and allowance information for all assets
+    ///
+    /// This returns the current balance and allowance for each asset in your account.
+    /// Balance is how much you own, allowance is how much the exchange can spend on your behalf.
+    ///
+    /// You need both balance and allowance to place orders - the exchange needs permission
+    /// to move your tokens when orders are filled.
+    pub async fn get_balance_allowance(
+        &self,
+        params: Option<crate::types::BalanceAllowanceParams>,
     ) -> Result<Value> {
         let signer = self
             .signer
@@ -1157,30 +2394,18 @@ impl ClobClient {
                 self.order_builder
                     .as_ref()
                     .expect("OrderBuilder not set")
-                    .get_sig_type(),
+                    .sig_type(),
             );
         }
 
         let query_params = params.to_query_params();
 
-        let method = Method::GET;
         let endpoint = "/balance-allowance";
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
-
         let response = self
-            .http_client
-            .request(method, self.clob_url(endpoint))
-            .headers(
-                headers
-                    .into_iter()
-                    .map(|(k, v)| (HeaderName::from_static(k), v.parse().unwrap()))
-                    .collect(),
-            )
-            .query(&query_params)
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send_l2_get(None, signer.as_ref(), api_creds, endpoint, |r| {
+                r.query(&query_params)
+            })
+            .await?;
 
         response
             .json::<Value>()
@@ -1206,32 +2431,18 @@ impl ClobClient {
             .as_ref()
             .ok_or_else(|| PolyError::auth("API credentials not set"))?;
 
-        let method = Method::GET;
         let endpoint = "/notifications";
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
-
+        let sig_type = self
+            .order_builder
+            .as_ref()
+            .expect("OrderBuilder not set")
+            .get_sig_type()
+            .to_string();
         let response = self
-            .http_client
-            .request(method, self.clob_url(endpoint))
-            .headers(
-                headers
-                    .into_iter()
-                    .map(|(k, v)| (HeaderName::from_static(k), v.parse().unwrap()))
-                    .collect(),
-            )
-            .query(&[(
-                "signature_type",
-                &self
-                    .order_builder
-                    .as_ref()
-                    .expect("OrderBuilder not set")
-                    .get_sig_type()
-                    .to_string(),
-            )])
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send_l2_get(None, signer.as_ref(), api_creds, endpoint, |r| {
+                r.query(&[("signature_type", &sig_type)])
+            })
+            .await?;
 
         response
             .json::<Value>()
@@ -1250,6 +2461,8 @@ impl ClobClient {
         &self,
         token_ids: &[String],
     ) -> Result<std::collections::HashMap<String, Decimal>> {
+        self.throttle(EndpointFamily::MarketData).await;
+
         let request_data: Vec<std::collections::HashMap<&str, String>> = token_ids
             .iter()
             .map(|id| {
@@ -1267,10 +2480,7 @@ impl ClobClient {
             .await?;
 
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to get batch midpoints",
-            ));
+            return Err(response_error(response, "Failed to get batch midpoints").await);
         }
 
         let midpoints: std::collections::HashMap<String, Decimal> = response.json().await?;
@@ -1288,6 +2498,8 @@ impl ClobClient {
         &self,
         book_params: &[crate::types::BookParams],
     ) -> Result<std::collections::HashMap<String, std::collections::HashMap<Side, Decimal>>> {
+        self.throttle(EndpointFamily::MarketData).await;
+
         let request_data: Vec<std::collections::HashMap<&str, String>> = book_params
             .iter()
             .map(|params| {
@@ -1306,10 +2518,7 @@ impl ClobClient {
             .await?;
 
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to get batch prices",
-            ));
+            return Err(response_error(response, "Failed to get batch prices").await);
         }
 
         let prices: std::collections::HashMap<String, std::collections::HashMap<Side, Decimal>> =
@@ -1319,6 +2528,8 @@ impl ClobClient {
 
     /// Get order book for multiple tokens (batch) - reference implementation compatible
     pub async fn get_order_books(&self, token_ids: &[String]) -> Result<Vec<OrderBookSummary>> {
+        self.throttle(EndpointFamily::MarketData).await;
+
         let request_data: Vec<std::collections::HashMap<&str, String>> = token_ids
             .iter()
             .map(|id| {
@@ -1353,23 +2564,14 @@ impl ClobClient {
             .as_ref()
             .ok_or_else(|| PolyError::config("API credentials not configured"))?;
 
-        let method = Method::GET;
         let endpoint = &format!("/data/order/{}", order_id);
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
-
         let response = self
-            .http_client
-            .request(method, self.clob_url(endpoint))
-            .headers(
-                headers
-                    .into_iter()
-                    .map(|(k, v)| (HeaderName::from_static(k), v.parse().unwrap()))
-                    .collect(),
-            )
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send_l2_get(None, signer.as_ref(), api_creds, endpoint, |r| r)
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(response_error(response, "Failed to get order").await);
+        }
 
         response
             .json::<crate::types::OpenOrder>()
@@ -1379,13 +2581,15 @@ impl ClobClient {
 
     /// Get last trade price for a token
     pub async fn get_last_trade_price(&self, token_id: &str) -> Result<Value> {
-        let response = self
+        self.throttle(EndpointFamily::MarketData).await;
+
+        let request = self
             .http_client
             .get(self.clob_url("last-trade-price"))
-            .query(&[("token_id", token_id)])
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .query(&[("token_id", token_id)]);
+        let response = self
+            .send_idempotent(Some(EndpointFamily::MarketData), request)
+            .await?;
 
         response
             .json::<Value>()
@@ -1395,6 +2599,8 @@ impl ClobClient {
 
     /// Get last trade prices for multiple tokens
     pub async fn get_last_trade_prices(&self, token_ids: &[String]) -> Result<Value> {
+        self.throttle(EndpointFamily::MarketData).await;
+
         let request_data: Vec<std::collections::HashMap<&str, String>> = token_ids
             .iter()
             .map(|id| {
@@ -1423,7 +2629,9 @@ impl ClobClient {
         &self,
         market: Option<&str>,
         asset_id: Option<&str>,
-    ) -> Result<Value> {
+    ) -> Result<CancelOrdersResponse> {
+        self.throttle(EndpointFamily::Cancels).await;
+
         let signer = self
             .signer
             .as_ref()
@@ -1440,7 +2648,14 @@ impl ClobClient {
             ("asset_id", asset_id.unwrap_or("")),
         ]);
 
-        let headers = create_l2_headers(signer, api_creds, method.as_str(), endpoint, Some(&body))?;
+        let headers = create_l2_headers_at(
+            signer,
+            api_creds,
+            method.as_str(),
+            endpoint,
+            Some(&body),
+            self.clock.now_secs(),
+        )?;
 
         let response = self
             .http_client
@@ -1456,8 +2671,12 @@ impl ClobClient {
             .await
             .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
 
+        if !response.status().is_success() {
+            return Err(response_error(response, "Failed to cancel market orders").await);
+        }
+
         response
-            .json::<Value>()
+            .json::<CancelOrdersResponse>()
             .await
             .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
     }
@@ -1475,8 +2694,14 @@ impl ClobClient {
 
         let method = Method::DELETE;
         let endpoint = "/notifications";
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
+        let headers = create_l2_headers_at::<_, Value>(
+            signer,
+            api_creds,
+            method.as_str(),
+            endpoint,
+            None,
+            self.clock.now_secs(),
+        )?;
 
         let response = self
             .http_client
@@ -1518,30 +2743,18 @@ impl ClobClient {
                 self.order_builder
                     .as_ref()
                     .expect("OrderBuilder not set")
-                    .get_sig_type(),
+                    .sig_type(),
             );
         }
 
         let query_params = params.to_query_params();
 
-        let method = Method::GET;
         let endpoint = "/balance-allowance/update";
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
-
         let response = self
-            .http_client
-            .request(method, self.clob_url(endpoint))
-            .headers(
-                headers
-                    .into_iter()
-                    .map(|(k, v)| (HeaderName::from_static(k), v.parse().unwrap()))
-                    .collect(),
-            )
-            .query(&query_params)
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send_l2_get(None, signer.as_ref(), api_creds, endpoint, |r| {
+                r.query(&query_params)
+            })
+            .await?;
 
         response
             .json::<Value>()
@@ -1560,24 +2773,12 @@ impl ClobClient {
             .as_ref()
             .ok_or_else(|| PolyError::config("API credentials not configured"))?;
 
-        let method = Method::GET;
         let endpoint = "/order-scoring";
-        let headers =
-            create_l2_headers::<Value>(signer, api_creds, method.as_str(), endpoint, None)?;
-
         let response = self
-            .http_client
-            .request(method, self.clob_url(endpoint))
-            .headers(
-                headers
-                    .into_iter()
-                    .map(|(k, v)| (HeaderName::from_static(k), v.parse().unwrap()))
-                    .collect(),
-            )
-            .query(&[("order_id", order_id)])
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send_l2_get(None, signer.as_ref(), api_creds, endpoint, |r| {
+                r.query(&[("order_id", order_id)])
+            })
+            .await?;
 
         let result: Value = response
             .json()
@@ -1603,12 +2804,13 @@ impl ClobClient {
 
         let method = Method::POST;
         let endpoint = "/orders-scoring";
-        let headers = create_l2_headers(
+        let headers = create_l2_headers_at(
             signer,
             api_creds,
             method.as_str(),
             endpoint,
             Some(order_ids),
+            self.clock.now_secs(),
         )?;
 
         let response = self
@@ -1631,20 +2833,180 @@ impl ClobClient {
             .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
     }
 
+    /// Fetch one page of markets with active liquidity reward programs
+    pub async fn get_reward_markets_page(
+        &self,
+        cursor: &str,
+    ) -> Result<Page<crate::types::RewardsMarket>> {
+        self.throttle(EndpointFamily::MarketData).await;
+
+        let request = self
+            .http_client
+            .get(self.clob_url("rewards/markets/current"))
+            .query(&[("next_cursor", cursor)]);
+        let response = self
+            .send_idempotent(Some(EndpointFamily::MarketData), request)
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(response_error(response, "Failed to get reward markets").await);
+        }
+
+        let mut body: Value = response.json().await?;
+        let data = serde_json::from_value(body["data"].take())
+            .map_err(|e| PolyError::parse(format!("Invalid reward markets: {}", e), None))?;
+        let next_cursor = body["next_cursor"]
+            .as_str()
+            .filter(|c| *c != END_CURSOR)
+            .map(str::to_owned);
+        Ok(Page { data, next_cursor })
+    }
+
+    /// Stream every market with an active liquidity reward program
+    pub fn get_reward_markets_stream(
+        &self,
+        next_cursor: Option<&str>,
+    ) -> impl Stream<Item = Result<Page<crate::types::RewardsMarket>>> + '_ {
+        paginate(
+            next_cursor.unwrap_or(INITIAL_CURSOR).to_string(),
+            move |cursor| async move { self.get_reward_markets_page(&cursor).await },
+        )
+    }
+
+    /// Get all markets with an active liquidity reward program
+    pub async fn get_reward_markets(&self) -> Result<Vec<crate::types::RewardsMarket>> {
+        let pages: Vec<_> = self.get_reward_markets_stream(None).try_collect().await?;
+        Ok(pages.into_iter().flat_map(|page| page.data).collect())
+    }
+
+    /// Fetch one page of the authenticated user's reward earnings for one day
+    pub async fn get_user_earnings_page(
+        &self,
+        date: chrono::NaiveDate,
+        cursor: &str,
+    ) -> Result<Page<crate::types::UserEarning>> {
+        let query = self.rewards_query(date);
+        let (data, next_cursor) = self.get_l2_page("/rewards/user", &query, cursor).await?;
+        let data = match data {
+            Value::Null => Vec::new(),
+            data => serde_json::from_value(data)
+                .map_err(|e| PolyError::parse(format!("Invalid earnings: {}", e), None))?,
+        };
+        Ok(Page { data, next_cursor })
+    }
+
+    /// Stream the authenticated user's reward earnings for one day
+    pub fn get_user_earnings_stream(
+        &self,
+        date: chrono::NaiveDate,
+        next_cursor: Option<&str>,
+    ) -> impl Stream<Item = Result<Page<crate::types::UserEarning>>> + '_ {
+        paginate(
+            next_cursor.unwrap_or(INITIAL_CURSOR).to_string(),
+            move |cursor| async move { self.get_user_earnings_page(date, &cursor).await },
+        )
+    }
+
+    /// Get the authenticated user's reward earnings per market for one day
+    pub async fn get_user_earnings(
+        &self,
+        date: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::UserEarning>> {
+        let pages: Vec<_> = self
+            .get_user_earnings_stream(date, None)
+            .try_collect()
+            .await?;
+        Ok(pages.into_iter().flat_map(|page| page.data).collect())
+    }
+
+    /// Get the authenticated user's total reward earnings for one day
+    pub async fn get_user_total_earnings(
+        &self,
+        date: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::TotalUserEarning>> {
+        let query = self.rewards_query(date);
+        self.get_l2_json("/rewards/user/total", &query).await
+    }
+
+    /// Get the authenticated user's share of each market's reward pool, in
+    /// percent, keyed by condition id
+    pub async fn get_reward_percentages(
+        &self,
+    ) -> Result<std::collections::HashMap<String, Decimal>> {
+        let query = [self.signature_type_param()];
+        self.get_l2_json("/rewards/user/percentages", &query).await
+    }
+
+    fn rewards_query(&self, date: chrono::NaiveDate) -> [(&'static str, String); 2] {
+        [
+            ("date", date.format("%Y-%m-%d").to_string()),
+            self.signature_type_param(),
+        ]
+    }
+
+    fn signature_type_param(&self) -> (&'static str, String) {
+        let sig_type = self
+            .order_builder
+            .as_ref()
+            .map(|builder| builder.get_sig_type())
+            .unwrap_or_default();
+        ("signature_type", sig_type.to_string())
+    }
+
+    /// GET an L2-authenticated endpoint and decode the JSON body
+    async fn get_l2_json<T, K, V>(&self, endpoint: &str, query_params: &[(K, V)]) -> Result<T>
+    where
+        T: DeserializeOwned,
+        K: serde::Serialize,
+        V: serde::Serialize,
+    {
+        self.throttle(EndpointFamily::MarketData).await;
+
+        let signer = self
+            .signer
+            .as_ref()
+            .ok_or_else(|| PolyError::auth("Signer not set"))?;
+        let api_creds = self
+            .api_creds
+            .as_ref()
+            .ok_or_else(|| PolyError::auth("API credentials not set"))?;
+
+        let response = self
+            .send_l2_get(
+                Some(EndpointFamily::MarketData),
+                signer.as_ref(),
+                api_creds,
+                endpoint,
+                |r| r.query(query_params),
+            )
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(response_error(response, &format!("Failed to get {}", endpoint)).await);
+        }
+
+        response
+            .json::<T>()
+            .await
+            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+    }
+
     /// Get sampling markets with pagination
     pub async fn get_sampling_markets(
         &self,
         next_cursor: Option<&str>,
     ) -> Result<crate::types::MarketsResponse> {
-        let next_cursor = next_cursor.unwrap_or("MA=="); // INITIAL_CURSOR
+        self.throttle(EndpointFamily::Gamma).await;
 
-        let response = self
+        let next_cursor = next_cursor.unwrap_or(INITIAL_CURSOR);
+
+        let request = self
             .http_client
             .get(self.gamma_url("sampling-markets"))
-            .query(&[("next_cursor", next_cursor)])
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .query(&[("next_cursor", next_cursor)]);
+        let response = self
+            .send_idempotent(Some(EndpointFamily::Gamma), request)
+            .await?;
 
         response
             .json::<crate::types::MarketsResponse>()
@@ -1652,20 +3014,40 @@ impl ClobClient {
             .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
     }
 
+    /// Stream sampling markets page by page, optionally resuming from a
+    /// saved cursor
+    pub fn get_sampling_markets_stream(
+        &self,
+        next_cursor: Option<&str>,
+    ) -> impl Stream<Item = Result<Page<crate::types::Market>>> + '_ {
+        paginate(
+            next_cursor.unwrap_or(INITIAL_CURSOR).to_string(),
+            move |cursor| async move {
+                let response = self.get_sampling_markets(Some(&cursor)).await?;
+                Ok(Page {
+                    data: response.data,
+                    next_cursor: response.next_cursor.filter(|c| c != END_CURSOR),
+                })
+            },
+        )
+    }
+
     /// Get sampling simplified markets with pagination
     pub async fn get_sampling_simplified_markets(
         &self,
         next_cursor: Option<&str>,
     ) -> Result<crate::types::SimplifiedMarketsResponse> {
-        let next_cursor = next_cursor.unwrap_or("MA=="); // INITIAL_CURSOR
+        self.throttle(EndpointFamily::Gamma).await;
 
-        let response = self
+        let next_cursor = next_cursor.unwrap_or(INITIAL_CURSOR);
+
+        let request = self
             .http_client
             .get(self.gamma_url("sampling-simplified-markets"))
-            .query(&[("next_cursor", next_cursor)])
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .query(&[("next_cursor", next_cursor)]);
+        let response = self
+            .send_idempotent(Some(EndpointFamily::Gamma), request)
+            .await?;
 
         response
             .json::<crate::types::SimplifiedMarketsResponse>()
@@ -1679,6 +3061,8 @@ impl ClobClient {
         next_cursor: Option<&str>,
         params: Option<&crate::types::GammaListParams>,
     ) -> Result<crate::types::MarketsResponse> {
+        self.throttle(EndpointFamily::Gamma).await;
+
         let offset = params
             .and_then(|options| options.offset.map(u64::from))
             .or_else(|| next_cursor.and_then(Self::decode_cursor))
@@ -1688,69 +3072,37 @@ impl ClobClient {
             .and_then(|options| options.limit)
             .unwrap_or(GAMMA_MARKETS_LIMIT);
 
-        let mut query = vec![("limit", limit.to_string()), ("offset", offset.to_string())];
-
-        // Always enforce a minimum liquidity threshold (default 10,000 when not specified).
-        let liquidity_min = params
-            .and_then(|options| options.liquidity_num_min)
-            .unwrap_or_else(|| Decimal::from(10_000));
-        query.push(("liquidity_num_min", liquidity_min.to_string()));
-
-        // Default end date to at least three weeks from now.
-        let min_end_date = Utc::now() + Duration::weeks(3);
-        let end_date_max = params
-            .and_then(|options| options.end_date_max)
-            .unwrap_or(min_end_date);
-        let end_date_max = if end_date_max < min_end_date {
-            min_end_date
-        } else {
-            end_date_max
-        };
-        query.push(("end_date_max", end_date_max.to_rfc3339()));
-
-        if let Some(start_date_min) = params.and_then(|options| options.start_date_min) {
-            query.push(("start_date_min", start_date_min.to_rfc3339()));
+        let mut options = params.cloned().unwrap_or_default();
+        options.limit = Some(limit);
+        options.offset = Some(u32::try_from(offset).unwrap_or(u32::MAX));
+        options.closed = options.closed.or(Some(false));
+
+        // Lookups by id, slug, token or condition skip the default floors so
+        // that thin or long-dated markets can still be found.
+        if !options.has_identifiers() {
+            // Always enforce a minimum liquidity threshold (default 10,000 when not specified).
+            options.liquidity_num_min = options
+                .liquidity_num_min
+                .or_else(|| Some(Decimal::from(10_000)));
+
+            // Default end date to three weeks from now; an explicit one is
+            // kept so markets ending sooner can be listed.
+            options.end_date_max = options
+                .end_date_max
+                .or_else(|| Some(Utc::now() + Duration::weeks(3)));
         }
+        let query = options.to_query_params();
 
-        if let Some(options) = params {
-            if let Some(closed) = options.closed {
-                query.push(("closed", closed.to_string()));
-            } else {
-                query.push(("closed", "false".to_string()));
-            }
+        let request = self
+            .http_client
+            .get(self.gamma_url("markets"))
+            .query(&query);
+        let response = self
+            .send_idempotent(Some(EndpointFamily::Gamma), request)
+            .await?;
 
-            if let Some(tag_id) = &options.tag_id {
-                query.push(("tag_id", tag_id.clone()));
-            }
-            if let Some(exclude_tag_id) = &options.exclude_tag_id {
-                query.push(("exclude_tag_id", exclude_tag_id.clone()));
-            }
-            if let Some(related_tags) = &options.related_tags {
-                query.push(("related_tags", related_tags.clone()));
-            }
-            if let Some(order) = &options.order {
-                query.push(("order", order.clone()));
-            }
-            if let Some(ascending) = options.ascending {
-                query.push(("ascending", ascending.to_string()));
-            }
-        } else {
-            query.push(("closed", "false".to_string()));
-        }
-
-        let response = self
-            .http_client
-            .get(self.gamma_url("markets"))
-            .query(&query)
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
-
-        if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to fetch markets",
-            ));
+        if !response.status().is_success() {
+            return Err(response_error(response, "Failed to fetch markets").await);
         }
 
         let body = response
@@ -1780,20 +3132,51 @@ impl ClobClient {
         })
     }
 
+    /// Stream Gamma markets page by page using offset pagination.
+    ///
+    /// `params.offset` (or a saved cursor) sets the starting point; every
+    /// yielded page carries the cursor for the next one.
+    pub fn get_markets_stream(
+        &self,
+        params: Option<crate::types::GammaListParams>,
+        next_cursor: Option<&str>,
+    ) -> impl Stream<Item = Result<Page<crate::types::Market>>> + '_ {
+        let mut params = params;
+        // Once streaming, the offset is carried by the cursor alone
+        let start_offset = params.as_mut().and_then(|options| options.offset.take());
+        let cursor = match (next_cursor, start_offset) {
+            (Some(cursor), _) => cursor.to_string(),
+            (None, offset) => Self::encode_cursor(offset.map(u64::from).unwrap_or(0)),
+        };
+
+        paginate(cursor, move |cursor| {
+            let params = params.clone();
+            async move {
+                let response = self.get_markets(Some(&cursor), params.as_ref()).await?;
+                Ok(Page {
+                    data: response.data,
+                    next_cursor: response.next_cursor,
+                })
+            }
+        })
+    }
+
     /// Get simplified markets with pagination
     pub async fn get_simplified_markets(
         &self,
         next_cursor: Option<&str>,
     ) -> Result<crate::types::SimplifiedMarketsResponse> {
-        let next_cursor = next_cursor.unwrap_or("MA=="); // INITIAL_CURSOR
+        self.throttle(EndpointFamily::Gamma).await;
 
-        let response = self
+        let next_cursor = next_cursor.unwrap_or(INITIAL_CURSOR);
+
+        let request = self
             .http_client
             .get(self.gamma_url("simplified-markets"))
-            .query(&[("next_cursor", next_cursor)])
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .query(&[("next_cursor", next_cursor)]);
+        let response = self
+            .send_idempotent(Some(EndpointFamily::Gamma), request)
+            .await?;
 
         response
             .json::<crate::types::SimplifiedMarketsResponse>()
@@ -1803,18 +3186,17 @@ impl ClobClient {
 
     /// Get single market by condition ID
     pub async fn get_market(&self, market_id: &str) -> Result<crate::types::Market> {
-        let response = self
+        self.throttle(EndpointFamily::Gamma).await;
+
+        let request = self
             .http_client
-            .get(self.gamma_url(&format!("markets/{}", market_id)))
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .get(self.gamma_url(&format!("markets/{}", market_id)));
+        let response = self
+            .send_idempotent(Some(EndpointFamily::Gamma), request)
+            .await?;
 
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to fetch Gamma market",
-            ));
+            return Err(response_error(response, "Failed to fetch Gamma market").await);
         }
 
         let body = response
@@ -1835,12 +3217,10 @@ impl ClobClient {
 
     /// Get market trades events
     pub async fn get_market_trades_events(&self, condition_id: &str) -> Result<Value> {
-        let response = self
+        let request = self
             .http_client
-            .get(self.clob_url(&format!("live-activity/events/{}", condition_id)))
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .get(self.clob_url(&format!("live-activity/events/{}", condition_id)));
+        let response = self.send_idempotent(None, request).await?;
 
         response
             .json::<Value>()
@@ -1853,22 +3233,20 @@ impl ClobClient {
         &self,
         params: Option<&crate::types::GammaListParams>,
     ) -> Result<Vec<crate::types::GammaEvent>> {
+        self.throttle(EndpointFamily::Gamma).await;
+
         let mut request = self.http_client.get(self.gamma_url("events"));
 
         if let Some(options) = params {
             request = request.query(&options.to_query_params());
         }
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+        let response = self
+            .send_idempotent(Some(EndpointFamily::Gamma), request)
+            .await?;
 
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to fetch Gamma events",
-            ));
+            return Err(response_error(response, "Failed to fetch Gamma events").await);
         }
 
         let payload: Value = response
@@ -1881,18 +3259,17 @@ impl ClobClient {
 
     /// Fetch a single Gamma event by slug
     pub async fn get_event_by_slug(&self, slug: &str) -> Result<crate::types::GammaEvent> {
-        let response = self
+        self.throttle(EndpointFamily::Gamma).await;
+
+        let request = self
             .http_client
-            .get(self.gamma_url(&format!("events/slug/{}", slug)))
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .get(self.gamma_url(&format!("events/slug/{}", slug)));
+        let response = self
+            .send_idempotent(Some(EndpointFamily::Gamma), request)
+            .await?;
 
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to fetch Gamma event",
-            ));
+            return Err(response_error(response, "Failed to fetch Gamma event").await);
         }
 
         response
@@ -1903,18 +3280,17 @@ impl ClobClient {
 
     /// Fetch a single Gamma event by numeric ID
     pub async fn get_event_by_id(&self, event_id: &str) -> Result<crate::types::GammaEvent> {
-        let response = self
+        self.throttle(EndpointFamily::Gamma).await;
+
+        let request = self
             .http_client
-            .get(self.gamma_url(&format!("events/{}", event_id)))
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .get(self.gamma_url(&format!("events/{}", event_id)));
+        let response = self
+            .send_idempotent(Some(EndpointFamily::Gamma), request)
+            .await?;
 
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to fetch Gamma event",
-            ));
+            return Err(response_error(response, "Failed to fetch Gamma event").await);
         }
 
         response
@@ -1925,18 +3301,15 @@ impl ClobClient {
 
     /// Fetch available Gamma tags
     pub async fn get_tags(&self) -> Result<Vec<crate::types::Tag>> {
+        self.throttle(EndpointFamily::Gamma).await;
+
+        let request = self.http_client.get(self.gamma_url("tags"));
         let response = self
-            .http_client
-            .get(self.gamma_url("tags"))
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send_idempotent(Some(EndpointFamily::Gamma), request)
+            .await?;
 
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to fetch Gamma tags",
-            ));
+            return Err(response_error(response, "Failed to fetch Gamma tags").await);
         }
 
         let payload: Value = response
@@ -1949,18 +3322,15 @@ impl ClobClient {
 
     /// Fetch available Gamma sports metadata
     pub async fn get_sports(&self) -> Result<Vec<crate::types::Sport>> {
+        self.throttle(EndpointFamily::Gamma).await;
+
+        let request = self.http_client.get(self.gamma_url("sports"));
         let response = self
-            .http_client
-            .get(self.gamma_url("sports"))
-            .send()
-            .await
-            .map_err(|e| PolyError::network(format!("Request failed: {}", e), e))?;
+            .send_idempotent(Some(EndpointFamily::Gamma), request)
+            .await?;
 
         if !response.status().is_success() {
-            return Err(PolyError::api(
-                response.status().as_u16(),
-                "Failed to fetch Gamma sports",
-            ));
+            return Err(response_error(response, "Failed to fetch Gamma sports").await);
         }
 
         let payload: Value = response
@@ -1971,6 +3341,109 @@ impl ClobClient {
         self.parse_gamma_list(payload, "Gamma sports")
     }
 
+    /// Search Gamma events, tags and profiles
+    pub async fn search(
+        &self,
+        params: &crate::types::GammaSearchParams,
+    ) -> Result<crate::types::GammaSearchResults> {
+        let payload = self
+            .gamma_get("public-search", &params.to_query_params(), "Gamma search")
+            .await?;
+        serde_json::from_value(payload)
+            .map_err(|e| PolyError::parse(format!("Failed to parse Gamma search: {}", e), None))
+    }
+
+    /// Fetch Gamma series with optional filtering
+    pub async fn get_series(
+        &self,
+        params: Option<&crate::types::GammaSeriesParams>,
+    ) -> Result<Vec<crate::types::GammaSeries>> {
+        let query = params.map(|p| p.to_query_params()).unwrap_or_default();
+        let payload = self.gamma_get("series", &query, "Gamma series").await?;
+        self.parse_gamma_list(payload, "Gamma series")
+    }
+
+    /// Fetch a single Gamma series by ID
+    pub async fn get_series_by_id(&self, series_id: &str) -> Result<crate::types::GammaSeries> {
+        let payload = self
+            .gamma_get(&format!("series/{}", series_id), &[], "Gamma series")
+            .await?;
+        serde_json::from_value(payload)
+            .map_err(|e| PolyError::parse(format!("Failed to parse Gamma series: {}", e), None))
+    }
+
+    /// Fetch comments, usually for one event, series or market
+    /// (see [`GammaCommentParams::for_entity`](crate::types::GammaCommentParams::for_entity))
+    pub async fn get_comments(
+        &self,
+        params: &crate::types::GammaCommentParams,
+    ) -> Result<Vec<crate::types::GammaComment>> {
+        let payload = self
+            .gamma_get("comments", &params.to_query_params(), "Gamma comments")
+            .await?;
+        self.parse_gamma_list(payload, "Gamma comments")
+    }
+
+    /// Fetch a comment together with its replies
+    pub async fn get_comment_thread(
+        &self,
+        comment_id: &str,
+    ) -> Result<Vec<crate::types::GammaComment>> {
+        let payload = self
+            .gamma_get(&format!("comments/{}", comment_id), &[], "Gamma comments")
+            .await?;
+        self.parse_gamma_list(payload, "Gamma comments")
+    }
+
+    /// Fetch comments written by a wallet
+    pub async fn get_comments_by_user(
+        &self,
+        address: &str,
+        params: Option<&crate::types::GammaCommentParams>,
+    ) -> Result<Vec<crate::types::GammaComment>> {
+        let query = params.map(|p| p.to_query_params()).unwrap_or_default();
+        let payload = self
+            .gamma_get(
+                &format!("comments/user_address/{}", address),
+                &query,
+                "Gamma comments",
+            )
+            .await?;
+        self.parse_gamma_list(payload, "Gamma comments")
+    }
+
+    /// Fetch the public profile of a wallet
+    pub async fn get_public_profile(&self, address: &str) -> Result<crate::types::GammaProfile> {
+        let payload = self
+            .gamma_get(
+                "public-profile",
+                &[("address", address.to_string())],
+                "Gamma profile",
+            )
+            .await?;
+        serde_json::from_value(payload)
+            .map_err(|e| PolyError::parse(format!("Failed to parse Gamma profile: {}", e), None))
+    }
+
+    /// GET a Gamma path and return the JSON body
+    async fn gamma_get(&self, path: &str, query: &[(&str, String)], ctx: &str) -> Result<Value> {
+        self.throttle(EndpointFamily::Gamma).await;
+
+        let request = self.http_client.get(self.gamma_url(path)).query(query);
+        let response = self
+            .send_idempotent(Some(EndpointFamily::Gamma), request)
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(response_error(response, &format!("Failed to fetch {}", ctx)).await);
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| PolyError::parse(format!("Failed to parse response: {}", e), None))
+    }
+
     fn parse_gamma_list<T>(&self, value: Value, ctx: &str) -> Result<Vec<T>>
     where
         T: DeserializeOwned,
@@ -1989,10 +3462,19 @@ impl ClobClient {
 
 // Re-export types from the canonical location in types.rs
 pub use crate::types::{
-    ExtraOrderArgs, GammaEvent, GammaListParams, Market, MarketOrderArgs, MarketsResponse,
-    MidpointResponse, NegRiskResponse, OrderBookSummary, OrderSummary, PriceResponse, Rewards,
-    Sport, SpreadResponse, Tag, TickSizeResponse, Token,
-    DataApiPositionsParams, DataApiSortBy, DataApiSortDirection, DataPosition, DataPositionValue,
+    BatchPostResult, CancelOrdersResponse, CommentEntityType, DataActivity, DataActivitySortBy,
+    DataActivityType, DataApiActivityParams, DataApiFilterType, DataApiHoldersParams,
+    DataApiLeaderboardParams, DataApiPositionsParams, DataApiSortBy, DataApiSortDirection,
+    DataApiTradesParams, DataHolder, DataLiveVolume, DataOpenInterest, DataPosition,
+    DataPositionValue, DataTokenHolders, DataTrade, ExtraOrderArgs, GammaComment,
+    GammaCommentParams, GammaEvent, GammaListParams, GammaPagination, GammaProfile,
+    GammaSearchParams, GammaSearchResults, GammaSeries, GammaSeriesParams, LeaderboardCategory,
+    LeaderboardEntry, LeaderboardOrderBy, LeaderboardPeriod, Market, MarketOrderArgs,
+    MarketsResponse, MidpointResponse, NegRiskResponse, OfflineOrder, OrderBookSummary,
+    OrderOutcome, OrderSummary, PostOrderResponse, PriceHistoryInterval, PriceHistoryParams,
+    PriceHistoryResponse, PricePoint, PriceResponse, ReplaceMode, ReplaceOutcome, Rewards,
+    RewardsConfig, RewardsMarket, Sport, SpreadResponse, Tag, TickSizeResponse, Token,
+    TotalUserEarning, UserEarning,
 };
 
 // Compatibility types that need to stay in client.rs
@@ -2021,7 +3503,7 @@ pub trait MarketClient: Send + Sync {
         &self,
         market: Option<&str>,
         asset_id: Option<&str>,
-    ) -> Result<serde_json::Value>;
+    ) -> Result<crate::types::CancelOrdersResponse>;
     async fn create_order(
         &self,
         order_args: &OrderArgs,
@@ -2033,7 +3515,7 @@ pub trait MarketClient: Send + Sync {
         &self,
         order: SignedOrderRequest,
         order_type: OrderType,
-    ) -> Result<serde_json::Value>;
+    ) -> Result<crate::types::PostOrderResponse>;
 }
 
 #[async_trait]
@@ -2061,7 +3543,7 @@ impl MarketClient for ClobClient {
         &self,
         market: Option<&str>,
         asset_id: Option<&str>,
-    ) -> Result<serde_json::Value> {
+    ) -> Result<crate::types::CancelOrdersResponse> {
         ClobClient::cancel_market_orders(self, market, asset_id).await
     }
 
@@ -2079,7 +3561,7 @@ impl MarketClient for ClobClient {
         &self,
         order: SignedOrderRequest,
         order_type: OrderType,
-    ) -> Result<serde_json::Value> {
+    ) -> Result<crate::types::PostOrderResponse> {
         ClobClient::post_order(self, order, order_type).await
     }
 }
@@ -2106,6 +3588,20 @@ mod tests {
         .with_gamma_base(base_url)
     }
 
+    fn create_test_client_with_l2_auth(base_url: &str) -> ClobClient {
+        ClobClient::with_l2_headers(
+            base_url,
+            "0x1234567890123456789012345678901234567890123456789012345678901234",
+            137,
+            ApiCredentials {
+                api_key: "test_key".to_string(),
+                secret: "dGVzdF9zZWNyZXQ=".to_string(),
+                passphrase: "test_passphrase".to_string(),
+            },
+        )
+        .with_gamma_base(base_url)
+    }
+
     #[tokio::test]
     async fn test_client_creation() {
         let client = create_test_client("https://test.example.com");
@@ -2114,6 +3610,203 @@ mod tests {
         assert!(client.api_creds.is_none());
     }
 
+    #[test]
+    fn test_builder_rejects_invalid_inputs() {
+        let err = ClobClient::builder("https://test.example.com")
+            .private_key("not-a-key")
+            .build()
+            .err()
+            .unwrap();
+        assert!(matches!(err, PolyError::Config { .. }));
+
+        let err = ClobClient::builder("https://test.example.com")
+            .private_key("0x1234567890123456789012345678901234567890123456789012345678901234")
+            .funder("0xnope")
+            .build()
+            .err()
+            .unwrap();
+        assert!(matches!(err, PolyError::Validation { .. }));
+
+        let err = ClobClient::builder("https://test.example.com")
+            .proxy("::not a url::")
+            .build()
+            .err()
+            .unwrap();
+        assert!(matches!(err, PolyError::Config { .. }));
+
+        let err = ClobClient::builder("https://test.example.com")
+            .http_client(Client::new())
+            .timeout(std::time::Duration::from_secs(1))
+            .build()
+            .err()
+            .unwrap();
+        assert!(matches!(err, PolyError::Config { .. }));
+    }
+
+    #[test]
+    fn test_builder_configures_client() {
+        let client = ClobClient::builder("https://test.example.com")
+            .chain_id(80002)
+            .private_key("0x1234567890123456789012345678901234567890123456789012345678901234")
+            .funder("0x0000000000000000000000000000000000000001")
+            .signature_type(SigType::PolyGnosisSafe)
+            .gamma_base("https://gamma.example.com")
+            .ws_base("wss://ws.example.com")
+            .rtds_base("wss://rtds.example.com")
+            .timeout(std::time::Duration::from_secs(5))
+            .connect_timeout(std::time::Duration::from_secs(1))
+            .user_agent("polysqueeze-test")
+            .build()
+            .unwrap();
+
+        assert_eq!(client.chain_id, 80002);
+        assert!(client.signer.is_some());
+        assert_eq!(client.gamma_base_url, "https://gamma.example.com");
+        assert_eq!(client.ws_base_url, "wss://ws.example.com");
+        assert_eq!(client.rtds_base_url, "wss://rtds.example.com");
+        assert_eq!(
+            client.order_builder.as_ref().unwrap().get_sig_type(),
+            SigType::PolyGnosisSafe as u8
+        );
+    }
+
+    #[test]
+    fn test_order_log_masks_owner() {
+        let single =
+            serde_json::json!({"order": {"salt": 1}, "owner": "test_key", "orderType": "GTC"});
+        let batch = serde_json::json!([single.clone(), single.clone()]);
+        for body in [redact_order_owner(&single), redact_order_owner(&batch)] {
+            let logged = body.to_string();
+            assert!(logged.contains("<redacted>"));
+            assert!(!logged.contains("test_key"));
+            assert!(logged.contains("GTC"));
+        }
+    }
+
+    #[test]
+    fn test_debug_output_is_redacted() {
+        let key = "0x1234567890123456789012345678901234567890123456789012345678901234";
+        let builder = ClobClient::builder("https://test.example.com").private_key(key);
+        let logged = format!("{:?}", builder);
+        assert!(logged.contains("<redacted>"));
+        assert!(!logged.contains(&key[2..]));
+
+        let logged = format!(
+            "{:?}",
+            create_test_client_with_l2_auth("https://test.example.com")
+        );
+        assert!(logged.contains("test_key"));
+        assert!(!logged.contains("dGVzdF9zZWNyZXQ="));
+        assert!(!logged.contains("test_passphrase"));
+    }
+
+    #[test]
+    fn test_builder_eoa_on_unknown_chain() {
+        let client = ClobClient::builder("https://test.example.com")
+            .chain_id(31337)
+            .private_key("0x1234567890123456789012345678901234567890123456789012345678901234")
+            .build()
+            .unwrap();
+        assert_eq!(client.funder(), client.address());
+
+        let result = ClobClient::builder("https://test.example.com")
+            .chain_id(31337)
+            .private_key("0x1234567890123456789012345678901234567890123456789012345678901234")
+            .signature_type(SigType::PolyProxy)
+            .build();
+        assert!(matches!(result, Err(PolyError::Config { .. })));
+    }
+
+    #[test]
+    fn test_builder_validates_funder_and_signature_type() {
+        let key = "0x1234567890123456789012345678901234567890123456789012345678901234";
+        let wallet = "0x0000000000000000000000000000000000000001";
+
+        let err = ClobClient::builder("https://test.example.com")
+            .private_key(key)
+            .funder(wallet)
+            .build()
+            .err()
+            .unwrap();
+        assert!(matches!(err, PolyError::Config { .. }));
+
+        let err = ClobClient::builder("https://test.example.com")
+            .private_key(key)
+            .funder(wallet)
+            .signature_type(SigType::Eoa)
+            .build()
+            .err()
+            .unwrap();
+        assert!(matches!(err, PolyError::Validation { .. }));
+
+        let err = ClobClient::builder("https://test.example.com")
+            .chain_id(1)
+            .private_key(key)
+            .signature_type(SigType::PolyProxy)
+            .build()
+            .err()
+            .unwrap();
+        assert!(matches!(err, PolyError::Config { .. }));
+
+        let mut client = create_test_client_with_auth("https://test.example.com");
+        client.set_funder(wallet, SigType::PolyGnosisSafe).unwrap();
+        let builder = client.order_builder.as_ref().unwrap();
+        assert_eq!(builder.sig_type(), SigType::PolyGnosisSafe);
+        assert_eq!(builder.funder(), Address::from_str(wallet).unwrap());
+
+        let mut order = batch_order(1);
+        order.signature_type = SigType::PolyGnosisSafe as u8;
+        assert!(order.validate(OrderType::GTC, 0).is_err());
+        order.maker = wallet.to_string();
+        order.signer = format!("0x{:040x}", 2);
+        assert!(order.validate(OrderType::GTC, 0).is_ok());
+    }
+
+    #[test]
+    fn test_builder_derives_funder_from_signature_type() {
+        let client = ClobClient::builder("https://test.example.com")
+            .private_key("0x1234567890123456789012345678901234567890123456789012345678901234")
+            .signature_type(SigType::PolyGnosisSafe)
+            .build()
+            .unwrap();
+        let signer = client.signer.as_ref().unwrap().address();
+        let builder = client.order_builder.as_ref().unwrap();
+        assert_eq!(builder.sig_type(), SigType::PolyGnosisSafe);
+        assert_eq!(
+            builder.funder(),
+            crate::wallet::derive_safe_wallet(signer, &crate::config::POLYGON_SAFE_FACTORY)
+        );
+
+        let mut client = client;
+        let proxy = client.set_signature_type(SigType::PolyProxy).unwrap();
+        assert_eq!(
+            proxy,
+            crate::wallet::derive_proxy_wallet(signer, &crate::config::POLYGON_PROXY_FACTORY)
+        );
+        assert_eq!(client.set_signature_type(SigType::Eoa).unwrap(), signer);
+    }
+
+    #[tokio::test]
+    async fn test_verify_order_uses_cached_neg_risk() {
+        let client = create_test_client_with_auth("http://127.0.0.1:9");
+        client
+            .metadata_cache()
+            .set_tick_size("1234", Decimal::from_str("0.01").unwrap());
+        client.metadata_cache().set_neg_risk("1234", true);
+
+        let args = OrderArgs::new(
+            "1234",
+            Decimal::from_str("0.4").unwrap(),
+            Decimal::from(5),
+            Side::SELL,
+        );
+        let order = client.create_order(&args, None, None, None).await.unwrap();
+        let order_id = client.verify_order(&order).await.unwrap();
+
+        assert_eq!(order_id, order.order_hash(137, true).unwrap().to_string());
+        assert!(order_id.starts_with("0x") && order_id.len() == 66);
+    }
+
     #[tokio::test]
     async fn test_client_with_l1_headers() {
         let client = create_test_client_with_auth("https://test.example.com");
@@ -2144,67 +3837,662 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_set_api_creds() {
-        let mut client = create_test_client("https://test.example.com");
-        assert!(client.api_creds.is_none());
+    async fn test_set_api_creds() {
+        let mut client = create_test_client("https://test.example.com");
+        assert!(client.api_creds.is_none());
+
+        let api_creds = ApiCredentials {
+            api_key: "test_key".to_string(),
+            secret: "test_secret".to_string(),
+            passphrase: "test_passphrase".to_string(),
+        };
+
+        client.set_api_creds(api_creds.clone());
+        assert!(client.api_creds.is_some());
+        assert_eq!(client.api_creds.unwrap().api_key, "test_key");
+    }
+
+    #[tokio::test]
+    async fn test_get_sampling_markets_success() {
+        let mut server = Server::new_async().await;
+        let mock_response = r#"{
+            "limit": "10",
+            "count": "2", 
+            "next_cursor": null,
+            "data": [
+                {
+                    "condition_id": "0x123",
+                    "tokens": [
+                        {"token_id": "0x456", "outcome": "Yes"},
+                        {"token_id": "0x789", "outcome": "No"}
+                    ],
+                    "rewards": {
+                        "rates": null,
+                        "min_size": "1.0",
+                        "max_spread": "0.1",
+                        "event_start_date": null,
+                        "event_end_date": null,
+                        "in_game_multiplier": null,
+                        "reward_epoch": null
+                    },
+                    "min_incentive_size": null,
+                    "max_incentive_spread": null,
+                    "active": true,
+                    "closed": false,
+                    "question_id": "0x123",
+                    "minimum_order_size": "1.0",
+                    "minimum_tick_size": "0.01",
+                    "description": "Test market",
+                    "category": "test",
+                    "end_date_iso": null,
+                    "game_start_time": null,
+                    "question": "Will this test pass?",
+                    "market_slug": "test-market",
+                    "seconds_delay": "0",
+                    "icon": "",
+                    "fpmm": ""
+                }
+            ]
+        }"#;
+
+        let mock = server
+            .mock("GET", "/sampling-markets")
+            .match_query(Matcher::UrlEncoded("next_cursor".into(), "MA==".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_response)
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let result = client.get_sampling_markets(None).await;
+
+        mock.assert_async().await;
+        assert!(result.is_ok());
+        let markets = result.unwrap();
+        assert_eq!(markets.data.len(), 1);
+        assert_eq!(markets.data[0].question, "Will this test pass?");
+    }
+
+    #[tokio::test]
+    async fn test_get_orders_page_surfaces_api_errors() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/data/orders")
+            .match_query(Matcher::Any)
+            .with_status(401)
+            .with_body(r#"{"error": "Unauthorized/Invalid api key"}"#)
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2_auth(&server.url());
+        let err = client.get_orders_page(None, "MA==").await.unwrap_err();
+
+        mock.assert_async().await;
+        assert!(
+            matches!(err, PolyError::Api { status: 401, .. }),
+            "{:?}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_trades_stream_follows_cursor() {
+        let mut server = Server::new_async().await;
+        let first = server
+            .mock("GET", "/data/trades")
+            .match_query(Matcher::UrlEncoded("next_cursor".into(), "MA==".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"next_cursor": "MQ==", "data": [{"id": "t1"}, {"id": "t2"}]}"#)
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/data/trades")
+            .match_query(Matcher::UrlEncoded("next_cursor".into(), "MQ==".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"next_cursor": "LTE=", "data": [{"id": "t3"}]}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        use crate::utils::rate_limit::RateLimit;
+        let client = ClobClient::builder(&server.url())
+            .private_key("0x1234567890123456789012345678901234567890123456789012345678901234")
+            .api_creds(ApiCredentials {
+                api_key: "test_key".to_string(),
+                secret: "dGVzdF9zZWNyZXQ=".to_string(),
+                passphrase: "test_passphrase".to_string(),
+            })
+            .rate_limits(
+                RateLimitConfig::new()
+                    .with_limit(EndpointFamily::AccountData, RateLimit::new(10, 10)),
+            )
+            .build()
+            .unwrap();
+        let pages: Vec<_> = client
+            .get_trades_stream(None, None)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].data.len(), 2);
+        assert_eq!(pages[0].next_cursor.as_deref(), Some("MQ=="));
+        assert_eq!(pages[1].data[0]["id"], "t3");
+        assert!(pages[1].next_cursor.is_none());
+
+        // Resuming from a saved cursor skips the pages already seen
+        let resumed: Vec<_> = client
+            .get_trades_stream(None, Some("MQ=="))
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(resumed.len(), 1);
+
+        first.assert_async().await;
+        second.assert_async().await;
+        // Every page takes a token
+        let stats = client
+            .rate_limit_stats(EndpointFamily::AccountData)
+            .unwrap();
+        assert_eq!(stats.requests, 3);
+    }
+
+    #[tokio::test]
+    async fn test_get_sampling_markets_with_cursor() {
+        let mut server = Server::new_async().await;
+        let mock_response = r#"{
+            "limit": "5",
+            "count": "0",
+            "next_cursor": null,
+            "data": []
+        }"#;
+
+        let mock = server
+            .mock("GET", "/sampling-markets")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "next_cursor".into(),
+                "test_cursor".into(),
+            )]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_response)
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let result = client.get_sampling_markets(Some("test_cursor")).await;
+
+        mock.assert_async().await;
+        assert!(result.is_ok());
+        let markets = result.unwrap();
+        assert_eq!(markets.data.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_get_order_book_success() {
+        let mut server = Server::new_async().await;
+        let mock_response = r#"{
+            "market": "0x123",
+            "asset_id": "0x123",
+            "hash": "0xabc123",
+            "timestamp": "1234567890",
+            "bids": [
+                {"price": "0.75", "size": "100.0"}
+            ],
+            "asks": [
+                {"price": "0.76", "size": "50.0"}
+            ]
+        }"#;
+
+        let mock = server
+            .mock("GET", "/book")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "0x123".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_response)
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let result = client.get_order_book("0x123").await;
+
+        mock.assert_async().await;
+        assert!(result.is_ok());
+        let book = result.unwrap();
+        assert_eq!(book.market, "0x123");
+        assert_eq!(book.bids.len(), 1);
+        assert_eq!(book.asks.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_market_data_requests_are_rate_limited() {
+        use crate::utils::rate_limit::RateLimit;
+
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/midpoint")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "0x123".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"mid": "0.5"}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = ClobClient::builder(&server.url())
+            .rate_limits(
+                RateLimitConfig::new()
+                    .with_limit(EndpointFamily::MarketData, RateLimit::new(1, 1)),
+            )
+            .build()
+            .unwrap();
+
+        client.get_midpoint("0x123").await.unwrap();
+        client.get_midpoint("0x123").await.unwrap();
+
+        mock.assert_async().await;
+        let stats = client.rate_limit_stats(EndpointFamily::MarketData).unwrap();
+        assert_eq!(stats.requests, 2);
+        assert_eq!(stats.delayed_requests, 1);
+        assert!(client.rate_limit_stats(EndpointFamily::Cancels).is_none());
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_rate_limits() {
+        use crate::utils::rate_limit::RateLimit;
+
+        for limit in [
+            RateLimit::new(0, 10),
+            RateLimit::new(10, 0),
+            RateLimit::new(10, 2_000_000_000),
+        ] {
+            let result = ClobClient::builder("https://test.example.com")
+                .rate_limits(RateLimitConfig::new().with_limit(EndpointFamily::OrderPosting, limit))
+                .build();
+            assert!(matches!(result, Err(PolyError::Config { .. })));
+        }
+
+        let config =
+            RateLimitConfig::new().with_limit(EndpointFamily::DataApi, RateLimit::new(0, 1));
+        assert!(DataApiClient::new().with_rate_limits(&config).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_heartbeats_do_not_use_cancel_budget() {
+        use crate::utils::rate_limit::RateLimit;
+
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/v1/heartbeats")
+            .with_status(200)
+            .with_body(r#"{"heartbeat_id": "hb-1"}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = ClobClient::builder(&server.url())
+            .private_key("0x1234567890123456789012345678901234567890123456789012345678901234")
+            .api_creds(ApiCredentials {
+                api_key: "test_key".to_string(),
+                secret: "dGVzdF9zZWNyZXQ=".to_string(),
+                passphrase: "test_passphrase".to_string(),
+            })
+            .rate_limits(
+                RateLimitConfig::new()
+                    .with_limit(EndpointFamily::Cancels, RateLimit::new(1, 1))
+                    .with_limit(EndpointFamily::Heartbeats, RateLimit::new(10, 10)),
+            )
+            .build()
+            .unwrap();
+        client.post_heartbeat(None).await.unwrap();
+        client.post_heartbeat(Some("hb-1")).await.unwrap();
+
+        mock.assert_async().await;
+        let heartbeats = client.rate_limit_stats(EndpointFamily::Heartbeats).unwrap();
+        assert_eq!(heartbeats.requests, 2);
+        let cancels = client.rate_limit_stats(EndpointFamily::Cancels).unwrap();
+        assert_eq!(cancels.requests, 0);
+    }
+
+    fn fast_retry() -> RetryConfig {
+        RetryConfig {
+            max_attempts: 3,
+            initial_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(5),
+            backoff_factor: 2.0,
+            jitter: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_idempotent_get_retries_server_errors() {
+        let mut server = Server::new_async().await;
+        let failing = server
+            .mock("GET", "/midpoint")
+            .match_query(Matcher::Any)
+            .with_status(503)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let client = ClobClient::builder(&server.url())
+            .retry(fast_retry())
+            .rate_limits(RateLimitConfig::new().with_limit(
+                EndpointFamily::MarketData,
+                crate::utils::rate_limit::RateLimit::new(10, 10),
+            ))
+            .build()
+            .unwrap();
+        let result = client.get_midpoint("0x123").await;
+
+        // The last response is surfaced once all attempts are used up
+        failing.assert_async().await;
+        assert!(matches!(result, Err(PolyError::Api { status: 503, .. })));
+        // Every attempt, retries included, takes a rate limit token
+        let stats = client.rate_limit_stats(EndpointFamily::MarketData).unwrap();
+        assert_eq!(stats.requests, 3);
+
+        server.reset();
+        let ok = server
+            .mock("GET", "/midpoint")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"mid": "0.5"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        assert!(client.get_midpoint("0x123").await.is_ok());
+        ok.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_response_parses_retry_after() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/midpoint")
+            .match_query(Matcher::Any)
+            .with_status(429)
+            .with_header("retry-after", "7")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let err = client.get_midpoint("0x123").await.unwrap_err();
+
+        mock.assert_async().await;
+        assert!(matches!(
+            err,
+            PolyError::RateLimit {
+                retry_after: Some(delay),
+                ..
+            } if delay == std::time::Duration::from_secs(7)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_long_retry_after_is_not_waited_out() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/midpoint")
+            .match_query(Matcher::Any)
+            .with_status(429)
+            .with_header("retry-after", "3600")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = ClobClient::builder(&server.url())
+            .retry(fast_retry())
+            .build()
+            .unwrap();
+        let err = client.get_midpoint("0x123").await.unwrap_err();
+
+        mock.assert_async().await;
+        assert!(matches!(
+            err,
+            PolyError::RateLimit {
+                retry_after: Some(delay),
+                ..
+            } if delay == std::time::Duration::from_secs(3600)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_signed_gets_are_rebuilt_for_every_attempt() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/data/order/0xabc")
+            .match_header("poly_api_key", "test_key")
+            .with_status(503)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let client = ClobClient::builder(&server.url())
+            .retry(fast_retry())
+            .build()
+            .unwrap();
+        let mut builds = 0;
+        let response = client
+            .send_rebuilt(None, || {
+                builds += 1;
+                Ok(client
+                    .http_client
+                    .get(client.clob_url("/data/order/0xabc"))
+                    .header("poly_api_key", "test_key"))
+            })
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(response.status(), 503);
+        assert_eq!(builds, 3);
+    }
+
+    #[tokio::test]
+    async fn test_post_order_is_never_retried() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/order")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = ClobClient::builder(&server.url())
+            .private_key("0x1234567890123456789012345678901234567890123456789012345678901234")
+            .api_creds(ApiCredentials {
+                api_key: "test_key".to_string(),
+                secret: "dGVzdF9zZWNyZXQ=".to_string(),
+                passphrase: "test_passphrase".to_string(),
+            })
+            .retry(fast_retry())
+            .build()
+            .unwrap();
+        let order = SignedOrderRequest {
+            salt: 1,
+            maker: "0x0".to_string(),
+            signer: "0x0".to_string(),
+            taker: "0x0".to_string(),
+            token_id: "1".to_string(),
+            maker_amount: "1".to_string(),
+            taker_amount: "1".to_string(),
+            expiration: "0".to_string(),
+            nonce: "0".to_string(),
+            fee_rate_bps: "0".to_string(),
+            side: "BUY".to_string(),
+            signature_type: 0,
+            signature: "0x".to_string(),
+        };
+
+        assert!(client.post_order(order, OrderType::GTC).await.is_err());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_post_offline_order_from_address_only_client() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/order")
+            .match_body(Matcher::PartialJson(
+                serde_json::json!({"orderType": "GTC"}),
+            ))
+            .with_status(200)
+            .with_body(r#"{"success": true, "errorMsg": "", "orderID": "0x01", "status": "live"}"#)
+            .create_async()
+            .await;
+
+        let cold: PrivateKeySigner =
+            "0x1234567890123456789012345678901234567890123456789012345678901234"
+                .parse()
+                .unwrap();
+        let address = PolySigner::address(&cold);
+        let builder = crate::orders::OrderBuilder::new(Arc::new(cold), None, None).unwrap();
+        let options = OrderOptions {
+            tick_size: Some(Decimal::from_str("0.01").unwrap()),
+            neg_risk: Some(false),
+            fee_rate_bps: None,
+        };
+        let args = OrderArgs::new(
+            "1234",
+            Decimal::from_str("0.3").unwrap(),
+            Decimal::from(5),
+            Side::BUY,
+        );
+        let order = builder
+            .sign_order_offline(&NetworkConfig::polygon_mainnet(), &args, 0, None, &options)
+            .await
+            .unwrap();
+        let json = OfflineOrder::new(
+            &NetworkConfig::polygon_mainnet(),
+            order,
+            OrderType::GTC,
+            false,
+        )
+        .unwrap()
+        .to_json()
+        .unwrap();
+
+        // Signed and packaged consistently, but for a foreign exchange
+        let mut foreign = NetworkConfig::polygon_mainnet();
+        for contract in foreign.contracts.values_mut() {
+            contract.exchange = Address::repeat_byte(9).to_string();
+        }
+        let foreign_order = builder
+            .sign_order_offline(&foreign, &args, 0, None, &options)
+            .await
+            .unwrap();
+        let foreign_order =
+            OfflineOrder::new(&foreign, foreign_order, OrderType::GTC, false).unwrap();
+
+        let client = ClobClient::builder(&server.url())
+            .signer(crate::auth::AddressOnlySigner(address))
+            .api_creds(ApiCredentials {
+                api_key: "test_key".to_string(),
+                secret: "dGVzdF9zZWNyZXQ=".to_string(),
+                passphrase: "test_passphrase".to_string(),
+            })
+            .build()
+            .unwrap();
+        let offline = OfflineOrder::from_json(&json).unwrap();
+
+        let mut wrong_chain = offline.clone();
+        wrong_chain.chain_id = 80002;
+        assert!(client.post_offline_order(wrong_chain).await.is_err());
+        let mut wrong_exchange = offline.clone();
+        wrong_exchange.exchange = crate::orders::exchange_address(137, true).unwrap();
+        assert!(client.post_offline_order(wrong_exchange).await.is_err());
+        assert!(matches!(
+            client.post_offline_order(foreign_order).await,
+            Err(PolyError::Validation { .. })
+        ));
+
+        let response = client.post_offline_order(offline).await.unwrap();
+        mock.assert_async().await;
+        assert_eq!(response.order_id, "0x01");
+    }
+
+    #[tokio::test]
+    async fn test_post_order_error_body_is_typed() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/order")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"error": "not enough balance / allowance"}"#)
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2_auth(&server.url());
+        let order = SignedOrderRequest {
+            salt: 1,
+            maker: "0x0".to_string(),
+            signer: "0x0".to_string(),
+            taker: "0x0".to_string(),
+            token_id: "1".to_string(),
+            maker_amount: "1".to_string(),
+            taker_amount: "1".to_string(),
+            expiration: "0".to_string(),
+            nonce: "0".to_string(),
+            fee_rate_bps: "0".to_string(),
+            side: "BUY".to_string(),
+            signature_type: 0,
+            signature: "0x".to_string(),
+        };
+        let err = client.post_order(order, OrderType::GTC).await.unwrap_err();
+
+        mock.assert_async().await;
+        match err {
+            PolyError::Order {
+                kind, error_code, ..
+            } => {
+                assert_eq!(kind, crate::errors::OrderErrorKind::InsufficientBalance);
+                assert_eq!(error_code, None);
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_midpoint_success() {
+        let mut server = Server::new_async().await;
+        let mock_response = r#"{
+            "mid": "0.755"
+        }"#;
 
-        let api_creds = ApiCredentials {
-            api_key: "test_key".to_string(),
-            secret: "test_secret".to_string(),
-            passphrase: "test_passphrase".to_string(),
-        };
+        let mock = server
+            .mock("GET", "/midpoint")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "0x123".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_response)
+            .create_async()
+            .await;
 
-        client.set_api_creds(api_creds.clone());
-        assert!(client.api_creds.is_some());
-        assert_eq!(client.api_creds.unwrap().api_key, "test_key");
+        let client = create_test_client(&server.url());
+        let result = client.get_midpoint("0x123").await;
+
+        mock.assert_async().await;
+        assert!(result.is_ok());
+        let response = result.unwrap();
+        assert_eq!(response.mid, Decimal::from_str("0.755").unwrap());
     }
 
     #[tokio::test]
-    async fn test_get_sampling_markets_success() {
+    async fn test_get_spread_success() {
         let mut server = Server::new_async().await;
         let mock_response = r#"{
-            "limit": "10",
-            "count": "2", 
-            "next_cursor": null,
-            "data": [
-                {
-                    "condition_id": "0x123",
-                    "tokens": [
-                        {"token_id": "0x456", "outcome": "Yes"},
-                        {"token_id": "0x789", "outcome": "No"}
-                    ],
-                    "rewards": {
-                        "rates": null,
-                        "min_size": "1.0",
-                        "max_spread": "0.1",
-                        "event_start_date": null,
-                        "event_end_date": null,
-                        "in_game_multiplier": null,
-                        "reward_epoch": null
-                    },
-                    "min_incentive_size": null,
-                    "max_incentive_spread": null,
-                    "active": true,
-                    "closed": false,
-                    "question_id": "0x123",
-                    "minimum_order_size": "1.0",
-                    "minimum_tick_size": "0.01",
-                    "description": "Test market",
-                    "category": "test",
-                    "end_date_iso": null,
-                    "game_start_time": null,
-                    "question": "Will this test pass?",
-                    "market_slug": "test-market",
-                    "seconds_delay": "0",
-                    "icon": "",
-                    "fpmm": ""
-                }
-            ]
+            "spread": "0.01"
         }"#;
 
         let mock = server
-            .mock("GET", "/sampling-markets")
-            .match_query(Matcher::UrlEncoded("next_cursor".into(), "MA==".into()))
+            .mock("GET", "/spread")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "0x123".into()))
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(mock_response)
@@ -2212,31 +4500,27 @@ mod tests {
             .await;
 
         let client = create_test_client(&server.url());
-        let result = client.get_sampling_markets(None).await;
+        let result = client.get_spread("0x123").await;
 
         mock.assert_async().await;
         assert!(result.is_ok());
-        let markets = result.unwrap();
-        assert_eq!(markets.data.len(), 1);
-        assert_eq!(markets.data[0].question, "Will this test pass?");
+        let response = result.unwrap();
+        assert_eq!(response.spread, Decimal::from_str("0.01").unwrap());
     }
 
     #[tokio::test]
-    async fn test_get_sampling_markets_with_cursor() {
+    async fn test_get_price_success() {
         let mut server = Server::new_async().await;
         let mock_response = r#"{
-            "limit": "5",
-            "count": "0",
-            "next_cursor": null,
-            "data": []
+            "price": "0.76"
         }"#;
 
         let mock = server
-            .mock("GET", "/sampling-markets")
-            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
-                "next_cursor".into(),
-                "test_cursor".into(),
-            )]))
+            .mock("GET", "/price")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("token_id".into(), "0x123".into()),
+                Matcher::UrlEncoded("side".into(), "BUY".into()),
+            ]))
             .with_status(200)
             .with_header("content-type", "application/json")
             .with_body(mock_response)
@@ -2244,32 +4528,23 @@ mod tests {
             .await;
 
         let client = create_test_client(&server.url());
-        let result = client.get_sampling_markets(Some("test_cursor")).await;
+        let result = client.get_price("0x123", Side::BUY).await;
 
         mock.assert_async().await;
         assert!(result.is_ok());
-        let markets = result.unwrap();
-        assert_eq!(markets.data.len(), 0);
+        let response = result.unwrap();
+        assert_eq!(response.price, Decimal::from_str("0.76").unwrap());
     }
 
     #[tokio::test]
-    async fn test_get_order_book_success() {
+    async fn test_get_tick_size_success() {
         let mut server = Server::new_async().await;
         let mock_response = r#"{
-            "market": "0x123",
-            "asset_id": "0x123",
-            "hash": "0xabc123",
-            "timestamp": "1234567890",
-            "bids": [
-                {"price": "0.75", "size": "100.0"}
-            ],
-            "asks": [
-                {"price": "0.76", "size": "50.0"}
-            ]
+            "minimum_tick_size": "0.01"
         }"#;
 
         let mock = server
-            .mock("GET", "/book")
+            .mock("GET", "/tick-size")
             .match_query(Matcher::UrlEncoded("token_id".into(), "0x123".into()))
             .with_status(200)
             .with_header("content-type", "application/json")
@@ -2278,142 +4553,598 @@ mod tests {
             .await;
 
         let client = create_test_client(&server.url());
-        let result = client.get_order_book("0x123").await;
+        let result = client.get_tick_size("0x123").await;
 
         mock.assert_async().await;
         assert!(result.is_ok());
-        let book = result.unwrap();
-        assert_eq!(book.market, "0x123");
-        assert_eq!(book.bids.len(), 1);
-        assert_eq!(book.asks.len(), 1);
+        let tick_size = result.unwrap();
+        assert_eq!(tick_size, Decimal::from_str("0.01").unwrap());
     }
 
     #[tokio::test]
-    async fn test_get_midpoint_success() {
+    async fn test_get_neg_risk_success() {
         let mut server = Server::new_async().await;
         let mock_response = r#"{
-            "mid": "0.755"
+            "neg_risk": false
         }"#;
 
         let mock = server
-            .mock("GET", "/midpoint")
+            .mock("GET", "/neg-risk")
             .match_query(Matcher::UrlEncoded("token_id".into(), "0x123".into()))
             .with_status(200)
-            .with_header("content-type", "application/json")
-            .with_body(mock_response)
+            .with_header("content-type", "application/json")
+            .with_body(mock_response)
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let result = client.get_neg_risk("0x123").await;
+
+        mock.assert_async().await;
+        assert!(result.is_ok());
+        let neg_risk = result.unwrap();
+        assert!(!neg_risk);
+    }
+
+    #[tokio::test]
+    async fn test_prewarmed_metadata_signs_without_http() {
+        let mut server = Server::new_async().await;
+        let tick_mock = server
+            .mock("GET", "/tick-size")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "123".into()))
+            .with_status(200)
+            .with_body(r#"{"minimum_tick_size": "0.01"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let neg_risk_mock = server
+            .mock("GET", "/neg-risk")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "123".into()))
+            .with_status(200)
+            .with_body(r#"{"neg_risk": true}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let fee_mock = server
+            .mock("GET", "/fee-rate")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "123".into()))
+            .with_status(200)
+            .with_body(r#"{"base_fee": 25}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_auth(&server.url());
+        client.prewarm_market_metadata(&["123"]).await.unwrap();
+        assert!(client.metadata_cache().is_warm("123"));
+
+        let args = OrderArgs::new(
+            "123",
+            Decimal::from_str("0.5").unwrap(),
+            Decimal::TEN,
+            Side::BUY,
+        );
+        let first = client.create_order(&args, None, None, None).await.unwrap();
+        let second = client.create_order(&args, None, None, None).await.unwrap();
+
+        tick_mock.assert_async().await;
+        neg_risk_mock.assert_async().await;
+        fee_mock.assert_async().await;
+        assert_eq!(first.fee_rate_bps, "25");
+        assert_eq!(second.fee_rate_bps, "25");
+    }
+
+    #[tokio::test]
+    async fn test_tick_size_change_refreshes_cached_tick_size() {
+        let mut server = Server::new_async().await;
+        let tick_mock = server
+            .mock("GET", "/tick-size")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "123".into()))
+            .with_status(200)
+            .with_body(r#"{"minimum_tick_size": "0.01"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/neg-risk")
+            .match_query(Matcher::UrlEncoded("token_id".into(), "123".into()))
+            .with_status(200)
+            .with_body(r#"{"neg_risk": false}"#)
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_auth(&server.url());
+        // 0.995 is above the 0.01 grid's max price but valid on a 0.001 grid
+        let args = OrderArgs::new(
+            "123",
+            Decimal::from_str("0.995").unwrap(),
+            Decimal::TEN,
+            Side::BUY,
+        );
+        assert!(client.create_order(&args, None, None, None).await.is_err());
+
+        let event = crate::wss::WssMarketEvent::TickSizeChange(crate::wss::TickSizeChangeMessage {
+            event_type: "tick_size_change".into(),
+            asset_id: "123".into(),
+            market: "0xabc".into(),
+            old_tick_size: Decimal::from_str("0.01").unwrap(),
+            new_tick_size: Decimal::from_str("0.001").unwrap(),
+            side: "BUY".into(),
+            timestamp: "0".into(),
+        });
+        client.metadata_cache().apply_market_event(&event);
+
+        assert!(client.create_order(&args, None, None, None).await.is_ok());
+        tick_mock.assert_async().await;
+
+        client.invalidate_market_metadata("123");
+        assert!(!client.metadata_cache().is_warm("123"));
+    }
+
+    #[tokio::test]
+    async fn test_sync_clock_offsets_auth_headers() {
+        let mut server = Server::new_async().await;
+        let server_now = crate::utils::time::now_secs() + 86_400;
+        let time_mock = server
+            .mock("GET", "/time")
+            .with_status(200)
+            .with_body(server_now.to_string())
+            .expect(3)
+            .create_async()
+            .await;
+        let prefix = &server_now.to_string()[..6];
+        let keys_mock = server
+            .mock("GET", "/auth/api-keys")
+            .match_header("poly_timestamp", Matcher::Regex(format!("^{}", prefix)))
+            .with_status(200)
+            .with_body(r#"{"apiKeys": []}"#)
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2_auth(&server.url());
+        let sample = client.sync_clock().await.unwrap();
+        time_mock.assert_async().await;
+
+        assert!(client.server_clock().is_synced());
+        assert_eq!(sample.server_secs, server_now);
+        let skew = client.server_clock().now_secs() as i64 - server_now as i64;
+        assert!(skew.abs() <= 2);
+        assert!(client.gtd_expiration(std::time::Duration::from_secs(30)) >= server_now + 88);
+
+        client.get_api_keys().await.unwrap();
+        keys_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_gtd_expiration_checked_against_server_clock() {
+        let mut server = Server::new_async().await;
+        let server_now = crate::utils::time::now_secs() + 86_400;
+        server
+            .mock("GET", "/time")
+            .with_status(200)
+            .with_body(server_now.to_string())
+            .create_async()
+            .await;
+        for (path, body) in [
+            ("/tick-size", r#"{"minimum_tick_size": "0.01"}"#),
+            ("/neg-risk", r#"{"neg_risk": false}"#),
+            ("/fee-rate", r#"{"base_fee": 0}"#),
+        ] {
+            server
+                .mock("GET", path)
+                .match_query(Matcher::Any)
+                .with_status(200)
+                .with_body(body)
+                .create_async()
+                .await;
+        }
+        let post_mock = server.mock("POST", "/order").expect(0).create_async().await;
+
+        let client = create_test_client_with_l2_auth(&server.url());
+        client.sync_clock().await.unwrap();
+
+        let args = OrderArgs::new(
+            "123",
+            Decimal::from_str("0.5").unwrap(),
+            Decimal::TEN,
+            Side::BUY,
+        );
+        let local_expiration = crate::utils::time::now_secs() + 3600;
+        let err = client
+            .create_order(&args, Some(local_expiration), None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PolyError::Validation { .. }));
+
+        let expiration = client.gtd_expiration(std::time::Duration::from_secs(3600));
+        let order = client
+            .create_order(&args, Some(expiration), None, None)
+            .await
+            .unwrap();
+        assert_eq!(order.expiration, expiration.to_string());
+
+        let stale = SignedOrderRequest {
+            expiration: local_expiration.to_string(),
+            ..order
+        };
+        let err = client.post_order(stale, OrderType::GTD).await.unwrap_err();
+        assert!(matches!(err, PolyError::Validation { .. }));
+        post_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_create_market_order_rejects_resting_order_types() {
+        let client = create_test_client_with_auth("http://127.0.0.1:9");
+        for order_type in [OrderType::GTC, OrderType::GTD] {
+            let args =
+                MarketOrderArgs::new("123", Decimal::TEN, Side::BUY).with_order_type(order_type);
+            let err = client
+                .create_market_order(&args, None, None)
+                .await
+                .unwrap_err();
+            assert!(matches!(err, PolyError::Validation { .. }));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_prices_history() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/prices-history")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("market".into(), "123".into()),
+                Matcher::UrlEncoded("startTs".into(), "1700000000".into()),
+                Matcher::UrlEncoded("endTs".into(), "1700007200".into()),
+                Matcher::UrlEncoded("fidelity".into(), "60".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                r#"{"history": [{"t": 1700003600, "p": 0.52}, {"t": 1700000000, "p": 0.5}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let params = PriceHistoryParams {
+            start_ts: Some(1_700_000_000),
+            end_ts: Some(1_700_007_200),
+            fidelity: Some(60),
+            ..Default::default()
+        };
+        let history = client.get_prices_history("123", &params).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].timestamp, 1_700_000_000);
+        assert_eq!(history[0].price, Decimal::from_str("0.5").unwrap());
+        assert_eq!(history[1].price, Decimal::from_str("0.52").unwrap());
+
+        let conflicting = PriceHistoryParams {
+            interval: Some(PriceHistoryInterval::OneDay),
+            start_ts: Some(1),
+            ..Default::default()
+        };
+        let err = client
+            .get_prices_history("123", &conflicting)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PolyError::Validation { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_get_reward_markets_follows_cursor() {
+        let mut server = Server::new_async().await;
+        let first = server
+            .mock("GET", "/rewards/markets/current")
+            .match_query(Matcher::UrlEncoded("next_cursor".into(), "MA==".into()))
+            .with_status(200)
+            .with_body(
+                r#"{"data": [{"condition_id": "0x1", "rewards_max_spread": 3.5, "rewards_min_size": 50,
+                    "rewards_config": [{"asset_address": "0xusdc", "rate_per_day": 25, "total_rewards": 500}]}],
+                   "next_cursor": "MQ=="}"#,
+            )
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/rewards/markets/current")
+            .match_query(Matcher::UrlEncoded("next_cursor".into(), "MQ==".into()))
+            .with_status(200)
+            .with_body(r#"{"data": [{"condition_id": "0x2"}], "next_cursor": "LTE="}"#)
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let markets = client.get_reward_markets().await.unwrap();
+
+        first.assert_async().await;
+        second.assert_async().await;
+        assert_eq!(markets.len(), 2);
+        assert_eq!(
+            markets[0].rewards_max_spread,
+            Decimal::from_str("3.5").unwrap()
+        );
+        assert_eq!(markets[0].rewards_config[0].rate_per_day, Decimal::from(25));
+        assert_eq!(markets[1].rewards_min_size, Decimal::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_get_user_earnings() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/rewards/user")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("date".into(), "2024-05-01".into()),
+                Matcher::UrlEncoded("signature_type".into(), "0".into()),
+                Matcher::UrlEncoded("next_cursor".into(), "MA==".into()),
+            ]))
+            .match_header("poly_api_key", "test_key")
+            .with_status(200)
+            .with_body(
+                r#"{"data": [{"date": "2024-05-01", "condition_id": "0x1", "asset_address": "0xusdc",
+                    "maker_address": "0xme", "earnings": 1.25, "asset_rate": 1}],
+                   "next_cursor": "MQ=="}"#,
+            )
+            .create_async()
+            .await;
+        let last_mock = server
+            .mock("GET", "/rewards/user")
+            .match_query(Matcher::UrlEncoded("next_cursor".into(), "MQ==".into()))
+            .with_status(200)
+            .with_body(r#"{"data": null, "next_cursor": "LTE="}"#)
+            .create_async()
+            .await;
+        let percentages_mock = server
+            .mock("GET", "/rewards/user/percentages")
+            .match_query(Matcher::UrlEncoded("signature_type".into(), "0".into()))
+            .with_status(200)
+            .with_body(r#"{"0x1": 12.5}"#)
             .create_async()
             .await;
 
-        let client = create_test_client(&server.url());
-        let result = client.get_midpoint("0x123").await;
+        let client = create_test_client_with_l2_auth(&server.url());
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        let earnings = client.get_user_earnings(date).await.unwrap();
+        let percentages = client.get_reward_percentages().await.unwrap();
 
         mock.assert_async().await;
-        assert!(result.is_ok());
-        let response = result.unwrap();
-        assert_eq!(response.mid, Decimal::from_str("0.755").unwrap());
+        last_mock.assert_async().await;
+        percentages_mock.assert_async().await;
+        assert_eq!(earnings.len(), 1);
+        assert_eq!(earnings[0].earnings, Decimal::from_str("1.25").unwrap());
+        assert_eq!(percentages["0x1"], Decimal::from_str("12.5").unwrap());
     }
 
     #[tokio::test]
-    async fn test_get_spread_success() {
+    async fn test_data_api_trades_and_activity() {
         let mut server = Server::new_async().await;
-        let mock_response = r#"{
-            "spread": "0.01"
-        }"#;
-
-        let mock = server
-            .mock("GET", "/spread")
-            .match_query(Matcher::UrlEncoded("token_id".into(), "0x123".into()))
+        let trades_mock = server
+            .mock("GET", "/trades")
+            .match_query(Matcher::Regex(
+                "^limit=10&filterType=CASH&filterAmount=100&market=0xa%2C0xb&side=BUY$".into(),
+            ))
             .with_status(200)
-            .with_header("content-type", "application/json")
-            .with_body(mock_response)
+            .with_body(
+                r#"[{"proxyWallet": "0xabc", "side": "BUY", "asset": "1", "conditionId": "0xa",
+                    "size": 250, "price": 0.42, "timestamp": 1700000000,
+                    "transactionHash": "0xhash"}]"#,
+            )
+            .create_async()
+            .await;
+        let activity_mock = server
+            .mock("GET", "/activity")
+            .match_query(Matcher::Regex(
+                "^type=SPLIT%2CMERGE&start=1700000000&user=0xabc$".into(),
+            ))
+            .with_status(200)
+            .with_body(
+                r#"[{"proxyWallet": "0xabc", "timestamp": 1700000100, "conditionId": "0xa",
+                    "type": "SPLIT", "size": 10, "usdcSize": 10},
+                   {"proxyWallet": "0xabc", "timestamp": 1700000200, "conditionId": "0xa",
+                    "type": "MAKER_REBATE", "size": 1, "usdcSize": 1}]"#,
+            )
             .create_async()
             .await;
 
-        let client = create_test_client(&server.url());
-        let result = client.get_spread("0x123").await;
-
-        mock.assert_async().await;
-        assert!(result.is_ok());
-        let response = result.unwrap();
-        assert_eq!(response.spread, Decimal::from_str("0.01").unwrap());
+        let client = DataApiClient::new().with_base_url(&server.url());
+        let trades = client
+            .get_trades(Some(DataApiTradesParams {
+                limit: Some(10),
+                filter_amount: Some(Decimal::from(100)),
+                markets: vec!["0xa".into(), "0xb".into()],
+                side: Some(Side::BUY),
+                ..Default::default()
+            }))
+            .await
+            .unwrap();
+        let activity = client
+            .get_activity(
+                "0xabc",
+                Some(DataApiActivityParams {
+                    types: vec![DataActivityType::Split, DataActivityType::Merge],
+                    start: Some(1_700_000_000),
+                    ..Default::default()
+                }),
+            )
+            .await
+            .unwrap();
+
+        trades_mock.assert_async().await;
+        activity_mock.assert_async().await;
+        assert_eq!(trades[0].price, Decimal::from_str("0.42").unwrap());
+        assert_eq!(trades[0].side, Side::BUY);
+        assert_eq!(activity[0].activity_type, DataActivityType::Split);
+        assert_eq!(activity[1].activity_type, DataActivityType::Unknown);
     }
 
     #[tokio::test]
-    async fn test_get_price_success() {
+    async fn test_data_api_holders_interest_and_leaderboard() {
         let mut server = Server::new_async().await;
-        let mock_response = r#"{
-            "price": "0.76"
-        }"#;
-
-        let mock = server
-            .mock("GET", "/price")
-            .match_query(Matcher::AllOf(vec![
-                Matcher::UrlEncoded("token_id".into(), "0x123".into()),
-                Matcher::UrlEncoded("side".into(), "BUY".into()),
-            ]))
+        let holders_mock = server
+            .mock("GET", "/holders")
+            .match_query(Matcher::Regex("^limit=5&market=0xa$".into()))
             .with_status(200)
-            .with_header("content-type", "application/json")
-            .with_body(mock_response)
+            .with_body(
+                r#"[{"token": "1", "holders": [{"proxyWallet": "0xabc", "amount": 1200.5,
+                    "outcomeIndex": 0}]}]"#,
+            )
+            .create_async()
+            .await;
+        let oi_mock = server
+            .mock("GET", "/oi")
+            .match_query(Matcher::UrlEncoded("market".into(), "0xa".into()))
+            .with_status(200)
+            .with_body(r#"[{"market": "0xa", "value": 5000}]"#)
+            .create_async()
+            .await;
+        let volume_mock = server
+            .mock("GET", "/live-volume")
+            .match_query(Matcher::UrlEncoded("id".into(), "42".into()))
+            .with_status(200)
+            .with_body(r#"[{"total": 900, "markets": [{"market": "0xa", "value": 900}]}]"#)
+            .create_async()
+            .await;
+        let leaderboard_mock = server
+            .mock("GET", "/v1/leaderboard")
+            .match_query(Matcher::Regex(
+                "^category=CRYPTO&timePeriod=WEEK&orderBy=VOL&limit=1$".into(),
+            ))
+            .with_status(200)
+            .with_body(
+                r#"[{"rank": "1", "proxyWallet": "0xabc", "userName": "whale",
+                    "vol": 1000000, "pnl": -20}]"#,
+            )
             .create_async()
             .await;
 
-        let client = create_test_client(&server.url());
-        let result = client.get_price("0x123", Side::BUY).await;
+        let client = DataApiClient::new().with_base_url(&server.url());
+        let markets = vec!["0xa".to_string()];
+        let holders = client
+            .get_holders(
+                &markets,
+                Some(DataApiHoldersParams {
+                    limit: Some(5),
+                    ..Default::default()
+                }),
+            )
+            .await
+            .unwrap();
+        let interest = client.get_open_interest(&markets).await.unwrap();
+        let volume = client.get_live_volume("42").await.unwrap();
+        let leaders = client
+            .get_leaderboard(Some(DataApiLeaderboardParams {
+                category: Some(LeaderboardCategory::Crypto),
+                time_period: Some(LeaderboardPeriod::Week),
+                order_by: Some(LeaderboardOrderBy::Volume),
+                limit: Some(1),
+                ..Default::default()
+            }))
+            .await
+            .unwrap();
 
-        mock.assert_async().await;
-        assert!(result.is_ok());
-        let response = result.unwrap();
-        assert_eq!(response.price, Decimal::from_str("0.76").unwrap());
+        holders_mock.assert_async().await;
+        oi_mock.assert_async().await;
+        volume_mock.assert_async().await;
+        leaderboard_mock.assert_async().await;
+        assert_eq!(
+            holders[0].holders[0].amount,
+            Decimal::from_str("1200.5").unwrap()
+        );
+        assert_eq!(interest[0].value, Decimal::from(5000));
+        assert_eq!(volume.markets[0].market, "0xa");
+        assert_eq!(leaders[0].pnl, Decimal::from(-20));
     }
 
     #[tokio::test]
-    async fn test_get_tick_size_success() {
+    async fn test_gamma_search() {
         let mut server = Server::new_async().await;
-        let mock_response = r#"{
-            "minimum_tick_size": "0.01"
-        }"#;
-
         let mock = server
-            .mock("GET", "/tick-size")
-            .match_query(Matcher::UrlEncoded("token_id".into(), "0x123".into()))
+            .mock("GET", "/public-search")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("q".into(), "election".into()),
+                Matcher::UrlEncoded("limit_per_type".into(), "5".into()),
+            ]))
             .with_status(200)
-            .with_header("content-type", "application/json")
-            .with_body(mock_response)
+            .with_body(
+                r#"{"events": [{"id": "1", "slug": "election-2028", "markets": []}],
+                   "tags": [{"id": "2", "slug": "politics"}],
+                   "profiles": [{"proxyWallet": "0xabc", "name": "trader"}],
+                   "pagination": {"hasMore": true, "totalResults": 40}}"#,
+            )
             .create_async()
             .await;
 
         let client = create_test_client(&server.url());
-        let result = client.get_tick_size("0x123").await;
+        let params = GammaSearchParams {
+            limit_per_type: Some(5),
+            ..GammaSearchParams::new("election")
+        };
+        let results = client.search(&params).await.unwrap();
 
         mock.assert_async().await;
-        assert!(result.is_ok());
-        let tick_size = result.unwrap();
-        assert_eq!(tick_size, Decimal::from_str("0.01").unwrap());
+        assert_eq!(results.events[0].slug, "election-2028");
+        assert_eq!(results.tags.len(), 1);
+        assert_eq!(results.profiles[0].proxy_wallet.as_deref(), Some("0xabc"));
+        assert!(results.pagination.has_more);
     }
 
     #[tokio::test]
-    async fn test_get_neg_risk_success() {
+    async fn test_gamma_series_comments_and_profile() {
         let mut server = Server::new_async().await;
-        let mock_response = r#"{
-            "neg_risk": false
-        }"#;
-
-        let mock = server
-            .mock("GET", "/neg-risk")
-            .match_query(Matcher::UrlEncoded("token_id".into(), "0x123".into()))
+        let series_mock = server
+            .mock("GET", "/series")
+            .match_query(Matcher::Regex("^slug=nba&slug=nfl$".into()))
             .with_status(200)
-            .with_header("content-type", "application/json")
-            .with_body(mock_response)
+            .with_body(r#"[{"id": "10", "slug": "nba", "title": "NBA", "recurrence": "daily"}]"#)
+            .create_async()
+            .await;
+        let comments_mock = server
+            .mock("GET", "/comments")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("parent_entity_type".into(), "Event".into()),
+                Matcher::UrlEncoded("parent_entity_id".into(), "42".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                r#"[{"id": "7", "body": "gl", "parentEntityType": "Event", "parentEntityID": 42,
+                    "userAddress": "0xabc", "profile": {"name": "trader"}}]"#,
+            )
+            .create_async()
+            .await;
+        let profile_mock = server
+            .mock("GET", "/public-profile")
+            .match_query(Matcher::UrlEncoded("address".into(), "0xabc".into()))
+            .with_status(200)
+            .with_body(
+                r#"{"proxyWallet": "0xabc", "pseudonym": "Quiet-Trader", "verifiedBadge": false}"#,
+            )
             .create_async()
             .await;
 
         let client = create_test_client(&server.url());
-        let result = client.get_neg_risk("0x123").await;
-
-        mock.assert_async().await;
-        assert!(result.is_ok());
-        let neg_risk = result.unwrap();
-        assert!(!neg_risk);
+        let series_params = GammaSeriesParams {
+            slug: vec!["nba".into(), "nfl".into()],
+            ..Default::default()
+        };
+        let series = client.get_series(Some(&series_params)).await.unwrap();
+        let comments = client
+            .get_comments(&GammaCommentParams::for_entity(
+                CommentEntityType::Event,
+                42,
+            ))
+            .await
+            .unwrap();
+        let profile = client.get_public_profile("0xabc").await.unwrap();
+
+        series_mock.assert_async().await;
+        comments_mock.assert_async().await;
+        profile_mock.assert_async().await;
+        assert_eq!(series[0].recurrence.as_deref(), Some("daily"));
+        assert_eq!(comments[0].parent_entity_id, Some(42));
+        assert_eq!(
+            comments[0].profile.as_ref().unwrap().name.as_deref(),
+            Some("trader")
+        );
+        assert_eq!(profile.pseudonym.as_deref(), Some("Quiet-Trader"));
     }
 
     #[tokio::test]
@@ -2526,6 +5257,90 @@ mod tests {
         assert_eq!(events[0].slug, "event-one");
     }
 
+    #[tokio::test]
+    async fn test_get_markets_keeps_explicit_end_date_max() {
+        let mut server = Server::new_async().await;
+        let end_date_max = chrono::DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let mock = server
+            .mock("GET", "/markets")
+            .match_query(Matcher::UrlEncoded(
+                "end_date_max".into(),
+                end_date_max.to_rfc3339(),
+            ))
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        client
+            .get_markets(
+                None,
+                Some(&GammaListParams {
+                    end_date_max: Some(end_date_max),
+                    ..Default::default()
+                }),
+            )
+            .await
+            .unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_gamma_list_filters_use_repeated_keys() {
+        let mut server = Server::new_async().await;
+        // Token lookups skip the default liquidity and end-date floors
+        let markets_mock = server
+            .mock("GET", "/markets")
+            .match_query(Matcher::Regex(
+                "^limit=2&offset=0&clob_token_ids=1&clob_token_ids=2&closed=false$".into(),
+            ))
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+        let events_mock = server
+            .mock("GET", "/events")
+            .match_query(Matcher::Regex(
+                "^slug=a&slug=b&active=true&tag_id=7&related_tags=true&volume_num_min=1000$".into(),
+            ))
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = create_test_client(&server.url());
+        let markets = client
+            .get_markets(
+                None,
+                Some(&GammaListParams {
+                    limit: Some(2),
+                    clob_token_ids: vec!["1".into(), "2".into()],
+                    ..Default::default()
+                }),
+            )
+            .await
+            .unwrap();
+        let events = client
+            .get_events(Some(&GammaListParams {
+                slug: vec!["a".into(), "b".into()],
+                active: Some(true),
+                tag_id: Some("7".into()),
+                related_tags: Some(true),
+                volume_num_min: Some(Decimal::from(1000)),
+                ..Default::default()
+            }))
+            .await
+            .unwrap();
+
+        markets_mock.assert_async().await;
+        events_mock.assert_async().await;
+        assert!(markets.data.is_empty());
+        assert!(events.is_empty());
+    }
+
     #[tokio::test]
     async fn test_get_gamma_event_by_slug_success() {
         let mut server = Server::new_async().await;
@@ -2599,6 +5414,276 @@ mod tests {
         assert_eq!(sports[0].name.as_deref(), Some("Soccer"));
     }
 
+    #[tokio::test]
+    async fn test_cancel_orders_typed_response() {
+        let mut server = Server::new_async().await;
+        let mock_response = r#"{
+            "canceled": ["0x1"],
+            "not_canceled": {"0x2": "order not found"}
+        }"#;
+
+        let mock = server
+            .mock("DELETE", "/orders")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_response)
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2_auth(&server.url());
+        let result = client
+            .cancel_orders(&["0x1".to_string(), "0x2".to_string()])
+            .await;
+
+        mock.assert_async().await;
+        let response = result.unwrap();
+        assert!(response.is_canceled("0x1"));
+        assert!(!response.all_canceled());
+        assert_eq!(
+            response.not_canceled.get("0x2").map(String::as_str),
+            Some("order not found")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_post_orders_typed_response() {
+        let mut server = Server::new_async().await;
+        let mock_response = r#"[
+            {"success": true, "errorMsg": "", "orderID": "0x1", "status": "live"},
+            {"success": false, "errorMsg": "invalid tick size", "orderID": ""}
+        ]"#;
+
+        let mock = server
+            .mock("POST", "/orders")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_response)
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2_auth(&server.url());
+        let order = SignedOrderRequest {
+            salt: 1,
+            maker: "0x0".to_string(),
+            signer: "0x0".to_string(),
+            taker: "0x0".to_string(),
+            token_id: "1".to_string(),
+            maker_amount: "1".to_string(),
+            taker_amount: "1".to_string(),
+            expiration: "0".to_string(),
+            nonce: "0".to_string(),
+            fee_rate_bps: "0".to_string(),
+            side: "BUY".to_string(),
+            signature_type: 0,
+            signature: "0x".to_string(),
+        };
+        let result = client
+            .post_orders(vec![order.clone(), order], OrderType::GTC)
+            .await;
+
+        mock.assert_async().await;
+        let responses = result.unwrap();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0].outcome, OrderOutcome::Live);
+        assert!(matches!(
+            &responses[1].outcome,
+            OrderOutcome::Rejected { reason } if reason == "invalid tick size"
+        ));
+    }
+
+    fn batch_order(salt: u64) -> SignedOrderRequest {
+        SignedOrderRequest {
+            salt,
+            maker: format!("0x{:040x}", 1),
+            signer: format!("0x{:040x}", 1),
+            taker: format!("0x{:040x}", 0),
+            token_id: "1".to_string(),
+            maker_amount: "1000000".to_string(),
+            taker_amount: "2000000".to_string(),
+            expiration: "0".to_string(),
+            nonce: "0".to_string(),
+            fee_rate_bps: "0".to_string(),
+            side: "BUY".to_string(),
+            signature_type: 0,
+            signature: format!("0x{:0130x}", salt),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_post_orders_batch_chunks_and_maps_results() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/orders")
+            .with_status(200)
+            .with_body_from_request(|request| {
+                let batch: Vec<Value> = serde_json::from_slice(request.body().unwrap()).unwrap();
+                let responses: Vec<Value> = batch
+                    .iter()
+                    .map(|entry| match entry["order"]["salt"].as_u64() {
+                        Some(16) => serde_json::json!({
+                            "success": false,
+                            "errorMsg": "not enough balance / allowance",
+                            "orderID": ""
+                        }),
+                        salt => serde_json::json!({
+                            "success": true,
+                            "errorMsg": "",
+                            "orderID": format!("0x{}", salt.unwrap()),
+                            "status": "live"
+                        }),
+                    })
+                    .collect();
+                serde_json::to_vec(&responses).unwrap()
+            })
+            .expect(2)
+            .create_async()
+            .await;
+
+        let mut orders: Vec<SignedOrderRequest> = (0..18).map(batch_order).collect();
+        orders[3].side = "HOLD".to_string();
+        orders[10] = batch_order(9);
+
+        let client = create_test_client_with_l2_auth(&server.url());
+        let batch = client
+            .post_orders_batch(orders, OrderType::GTC)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(batch.results.len(), 18);
+        assert!(!batch.all_succeeded());
+        assert_eq!(batch.results[0].as_ref().unwrap().order_id, "0x0");
+        assert_eq!(batch.results[17].as_ref().unwrap().order_id, "0x17");
+
+        let failed: Vec<usize> = batch.failed().map(|(i, _)| i).collect();
+        assert_eq!(failed, vec![3, 10, 16]);
+        assert!(matches!(
+            batch.results[3],
+            Err(PolyError::Validation { .. })
+        ));
+        assert!(matches!(
+            batch.results[10],
+            Err(PolyError::Order {
+                kind: crate::errors::OrderErrorKind::DuplicateOrder,
+                ..
+            })
+        ));
+        assert!(matches!(
+            batch.results[16],
+            Err(PolyError::Order {
+                kind: crate::errors::OrderErrorKind::InsufficientBalance,
+                ..
+            })
+        ));
+        assert_eq!(batch.succeeded().count(), 15);
+    }
+
+    #[tokio::test]
+    async fn test_replace_order_cancel_then_post() {
+        let mut server = Server::new_async().await;
+        let cancel_mock = server
+            .mock("DELETE", "/order")
+            .with_status(200)
+            .with_body(r#"{"canceled": ["0xold"], "not_canceled": {}}"#)
+            .create_async()
+            .await;
+        let post_mock = server
+            .mock("POST", "/order")
+            .with_status(200)
+            .with_body(r#"{"success": true, "errorMsg": "", "orderID": "0xnew", "status": "live"}"#)
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2_auth(&server.url());
+        let outcome = client
+            .replace_order(
+                "0xold",
+                batch_order(1),
+                OrderType::GTC,
+                ReplaceMode::CancelThenPost,
+            )
+            .await
+            .unwrap();
+
+        cancel_mock.assert_async().await;
+        post_mock.assert_async().await;
+        match outcome {
+            ReplaceOutcome::Replaced { new_order } => assert_eq!(new_order.order_id, "0xnew"),
+            other => panic!("unexpected outcome: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replace_order_cancel_failure_skips_post() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("DELETE", "/order")
+            .with_status(200)
+            .with_body(r#"{"canceled": [], "not_canceled": {"0xold": "order not found"}}"#)
+            .create_async()
+            .await;
+        let post_mock = server.mock("POST", "/order").expect(0).create_async().await;
+
+        let client = create_test_client_with_l2_auth(&server.url());
+        let outcome = client
+            .replace_order(
+                "0xold",
+                batch_order(1),
+                OrderType::GTC,
+                ReplaceMode::CancelThenPost,
+            )
+            .await
+            .unwrap();
+
+        post_mock.assert_async().await;
+        assert!(matches!(
+            outcome,
+            ReplaceOutcome::CancelFailed {
+                error: PolyError::Order {
+                    kind: crate::errors::OrderErrorKind::OrderNotFound,
+                    ..
+                },
+                new_order: None,
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_replace_order_post_then_cancel_keeps_old_on_rejection() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("POST", "/order")
+            .with_status(200)
+            .with_body(r#"{"success": false, "errorMsg": "invalid tick size", "orderID": ""}"#)
+            .create_async()
+            .await;
+        let cancel_mock = server
+            .mock("DELETE", "/order")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let client = create_test_client_with_l2_auth(&server.url());
+        let outcome = client
+            .replace_order(
+                "0xold",
+                batch_order(1),
+                OrderType::GTC,
+                ReplaceMode::PostThenCancel,
+            )
+            .await
+            .unwrap();
+
+        cancel_mock.assert_async().await;
+        assert!(matches!(
+            outcome,
+            ReplaceOutcome::PostFailed {
+                old_cancelled: false,
+                ..
+            }
+        ));
+    }
+
     #[test]
     fn test_client_configuration() {
         let client = create_test_client("https://test.example.com");
@@ -3,6 +3,7 @@
 //! This module contains contract addresses and configuration for different
 //! networks and environments.
 
+use alloy_primitives::{Address, B256, address, b256};
 use std::collections::HashMap;
 
 /// Contract configuration for a specific network
@@ -51,6 +52,26 @@ pub fn get_contract_config(chain_id: u64, neg_risk: bool) -> Option<ContractConf
     }
 }
 
+/// CREATE2 parameters of a wallet factory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WalletFactory {
+    pub address: Address,
+    /// keccak256 of the init code the factory deploys
+    pub init_code_hash: B256,
+}
+
+/// Factory that deploys Polymarket proxy wallets (email/magic accounts)
+pub const POLYGON_PROXY_FACTORY: WalletFactory = WalletFactory {
+    address: address!("0xaB45c5A4B0c941a2F231C04C3f49182e1A254052"),
+    init_code_hash: b256!("0xd21df8dc65880a8606f09fe0ce3df9b8869287ab0b058be05aa9e8af6330a00b"),
+};
+
+/// Factory that deploys Polymarket Gnosis Safes (browser-wallet accounts)
+pub const POLYGON_SAFE_FACTORY: WalletFactory = WalletFactory {
+    address: address!("0xaacFeEa03eb1561C4e67d661e40682Bd20E3541b"),
+    init_code_hash: b256!("0x2bce2127ff07fb632d16c8347c4ebf501f4841168bed00d9e6ef715ddb6fcecf"),
+};
+
 /// Network configuration
 #[derive(Debug, Clone)]
 pub struct NetworkConfig {
@@ -59,9 +80,22 @@ pub struct NetworkConfig {
     pub rpc_url: String,
     pub block_explorer: String,
     pub contracts: HashMap<String, ContractConfig>,
+    /// Proxy wallet factory, when deployed on this network
+    pub proxy_factory: Option<WalletFactory>,
+    /// Gnosis Safe factory, when deployed on this network
+    pub safe_factory: Option<WalletFactory>,
 }
 
 impl NetworkConfig {
+    /// Get configuration for a known chain id
+    pub fn for_chain(chain_id: u64) -> Option<Self> {
+        match chain_id {
+            137 => Some(Self::polygon_mainnet()),
+            80002 => Some(Self::polygon_mumbai()),
+            _ => None,
+        }
+    }
+
     /// Get configuration for Polygon mainnet
     pub fn polygon_mainnet() -> Self {
         let mut contracts = HashMap::new();
@@ -80,6 +114,8 @@ impl NetworkConfig {
             rpc_url: "https://polygon-rpc.com".to_string(),
             block_explorer: "https://polygonscan.com".to_string(),
             contracts,
+            proxy_factory: Some(POLYGON_PROXY_FACTORY),
+            safe_factory: Some(POLYGON_SAFE_FACTORY),
         }
     }
 
@@ -101,6 +137,9 @@ impl NetworkConfig {
             rpc_url: "https://rpc-mumbai.maticvigil.com".to_string(),
             block_explorer: "https://mumbai.polygonscan.com".to_string(),
             contracts,
+            // Only the Safe factory is deployed on the testnet
+            proxy_factory: None,
+            safe_factory: Some(POLYGON_SAFE_FACTORY),
         }
     }
 
@@ -3,7 +3,7 @@
 //! This module provides high-performance decoding functions for various
 //! data formats used in trading environments.
 
-use crate::errors::{PolyError, Result};
+use crate::errors::{OrderErrorKind, PolyError, Result};
 use crate::types::*;
 use alloy_primitives::{Address, U256};
 use chrono::{DateTime, Utc};
@@ -192,6 +192,24 @@ pub struct RawToken {
     pub outcome: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct RawPostOrderResponse {
+    #[serde(default)]
+    pub success: Option<bool>,
+    #[serde(rename = "errorMsg", default)]
+    pub error_msg: Option<String>,
+    #[serde(rename = "orderID", default)]
+    pub order_id: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(rename = "makingAmount", default)]
+    pub making_amount: Option<String>,
+    #[serde(rename = "takingAmount", default)]
+    pub taking_amount: Option<String>,
+    #[serde(rename = "transactionsHashes", default)]
+    pub transactions_hashes: Option<Vec<String>>,
+}
+
 /// Decoder implementations for converting raw responses to client types
 pub trait Decoder<T> {
     fn decode(&self) -> Result<T>;
@@ -373,6 +391,176 @@ impl Decoder<Market> for RawMarketResponse {
     }
 }
 
+impl Decoder<PostOrderResponse> for RawPostOrderResponse {
+    fn decode(&self) -> Result<PostOrderResponse> {
+        let error_msg = self.error_msg.as_deref().unwrap_or("").trim();
+
+        let outcome = if self.success == Some(false) || !error_msg.is_empty() {
+            OrderOutcome::Rejected {
+                reason: error_msg.to_string(),
+            }
+        } else {
+            let status = self.status.as_deref().unwrap_or("").trim();
+            match status.to_ascii_lowercase().as_str() {
+                "matched" => OrderOutcome::Matched,
+                "live" => OrderOutcome::Live,
+                "delayed" => OrderOutcome::Delayed,
+                "unmatched" => OrderOutcome::Unmatched,
+                // Accepted all the same; failing here would invite a re-post
+                _ => OrderOutcome::Unknown(status.to_string()),
+            }
+        };
+
+        let parse_amount = |raw: &Option<String>| -> Result<Option<Decimal>> {
+            match raw.as_deref().map(str::trim) {
+                None | Some("") => Ok(None),
+                Some(s) => fast_parse::parse_decimal(s).map(Some),
+            }
+        };
+
+        Ok(PostOrderResponse {
+            order_id: self.order_id.clone().unwrap_or_default(),
+            outcome,
+            making_amount: parse_amount(&self.making_amount)?,
+            taking_amount: parse_amount(&self.taking_amount)?,
+            transaction_hashes: self.transactions_hashes.clone().unwrap_or_default(),
+        })
+    }
+}
+
+impl TryFrom<RawPostOrderResponse> for PostOrderResponse {
+    type Error = PolyError;
+
+    fn try_from(raw: RawPostOrderResponse) -> Result<Self> {
+        raw.decode()
+    }
+}
+
+/// Error payload returned by the CLOB, e.g. `{"error": "invalid tick size"}`
+#[derive(Debug, Deserialize)]
+pub struct RawErrorResponse {
+    #[serde(alias = "errorMsg", alias = "message", default)]
+    pub error: Option<String>,
+    #[serde(alias = "errorCode", alias = "code", default)]
+    pub error_code: Option<String>,
+}
+
+/// Known CLOB order failures: server phrases, error code, typed kind.
+///
+/// Phrases are matched as whole server wordings so unrelated messages that
+/// merely mention e.g. an allowance are not misclassified.
+const ORDER_ERRORS: &[(&[&str], &str, OrderErrorKind)] = &[
+    (
+        &["not enough balance / allowance"],
+        "INVALID_ORDER_NOT_ENOUGH_BALANCE",
+        OrderErrorKind::InsufficientBalance,
+    ),
+    (
+        &["breaks minimum tick size rule"],
+        "INVALID_ORDER_MIN_TICK_SIZE",
+        OrderErrorKind::PriceConstraint,
+    ),
+    (
+        &["order crosses book"],
+        "INVALID_POST_ONLY_ORDER",
+        OrderErrorKind::PriceConstraint,
+    ),
+    (
+        &["lower than the minimum"],
+        "INVALID_ORDER_MIN_SIZE",
+        OrderErrorKind::SizeConstraint,
+    ),
+    (
+        &["is invalid. duplicated"],
+        "INVALID_ORDER_DUPLICATED",
+        OrderErrorKind::DuplicateOrder,
+    ),
+    (
+        &["the market is not yet ready to process new orders"],
+        "MARKET_NOT_READY",
+        OrderErrorKind::MarketClosed,
+    ),
+    (
+        &["couldn't be fully filled"],
+        "FOK_ORDER_NOT_FILLED_ERROR",
+        OrderErrorKind::ExecutionFailed,
+    ),
+    (
+        &["could not run the execution"],
+        "EXECUTION_ERROR",
+        OrderErrorKind::ExecutionFailed,
+    ),
+    (
+        &["order not found", "order does not exist"],
+        "ORDER_NOT_FOUND",
+        OrderErrorKind::OrderNotFound,
+    ),
+    (
+        &["invalid price"],
+        "INVALID_ORDER_PRICE",
+        OrderErrorKind::InvalidPrice,
+    ),
+    (
+        &["invalid size", "invalid amount"],
+        "INVALID_ORDER_SIZE",
+        OrderErrorKind::InvalidSize,
+    ),
+];
+
+/// Map a CLOB error message to its error code and [`OrderErrorKind`]
+pub fn classify_order_error(message: &str) -> Option<(&'static str, OrderErrorKind)> {
+    let message = message.to_ascii_lowercase();
+    ORDER_ERRORS
+        .iter()
+        .find(|(needles, _, _)| needles.iter().any(|needle| message.contains(needle)))
+        .map(|(_, code, kind)| (*code, kind.clone()))
+}
+
+/// Decode a non-success CLOB response body into a [`PolyError`].
+///
+/// Recognised order failures become [`PolyError::Order`] with the matching
+/// kind and the server's own error code, if any; anything else is reported as [`PolyError::Api`] with
+/// the server's message appended to `context`.
+pub fn decode_error_response(status: u16, body: &str, context: &str) -> PolyError {
+    let raw = serde_json::from_str::<RawErrorResponse>(body).ok();
+    let server_code = raw.as_ref().and_then(|raw| raw.error_code.clone());
+    let server_message = raw
+        .and_then(|raw| raw.error)
+        .unwrap_or_else(|| body.trim().to_string());
+
+    if let Some((_, kind)) = classify_order_error(&server_message) {
+        return PolyError::Order {
+            message: format!("{}: {}", context, server_message),
+            kind,
+            error_code: server_code,
+        };
+    }
+
+    let message = if server_message.is_empty() {
+        context.to_string()
+    } else {
+        format!("{}: {}", context, server_message)
+    };
+    PolyError::Api {
+        status,
+        message,
+        error_code: server_code,
+    }
+}
+
+/// Turn the reason of a rejected order into a typed [`PolyError::Order`].
+/// Unrecognised reasons are reported as [`OrderErrorKind::ExecutionFailed`].
+pub fn order_rejection_error(reason: &str) -> PolyError {
+    let (code, kind) = classify_order_error(reason)
+        .map(|(code, kind)| (Some(code.to_string()), kind))
+        .unwrap_or((None, OrderErrorKind::ExecutionFailed));
+    PolyError::Order {
+        message: format!("Order rejected: {}", reason),
+        kind,
+        error_code: code,
+    }
+}
+
 /// WebSocket message parsing
 pub fn parse_stream_message(raw: &str) -> Result<StreamMessage> {
     let value: Value = serde_json::from_str(raw)?;
@@ -538,6 +726,133 @@ mod tests {
         assert!(fast_parse::parse_side("invalid").is_err());
     }
 
+    #[test]
+    fn test_post_order_response_outcomes() {
+        let matched: PostOrderResponse = serde_json::from_str(
+            r#"{"success":true,"errorMsg":"","orderID":"0xabc","status":"matched","makingAmount":"5","takingAmount":"10","transactionsHashes":["0x01"]}"#,
+        )
+        .unwrap();
+        assert_eq!(matched.order_id, "0xabc");
+        assert_eq!(matched.outcome, OrderOutcome::Matched);
+        assert_eq!(matched.making_amount, Some(Decimal::from(5)));
+        assert_eq!(matched.transaction_hashes, vec!["0x01".to_string()]);
+
+        let live: PostOrderResponse = serde_json::from_str(
+            r#"{"success":true,"errorMsg":"","orderID":"0xdef","status":"live","makingAmount":"","takingAmount":""}"#,
+        )
+        .unwrap();
+        assert_eq!(live.outcome, OrderOutcome::Live);
+        assert_eq!(live.making_amount, None);
+
+        let rejected: PostOrderResponse = serde_json::from_str(
+            r#"{"success":false,"errorMsg":"not enough balance / allowance","orderID":""}"#,
+        )
+        .unwrap();
+        assert!(!rejected.is_success());
+        assert_eq!(
+            rejected.outcome,
+            OrderOutcome::Rejected {
+                reason: "not enough balance / allowance".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_post_order_response_unknown_status() {
+        let response: PostOrderResponse =
+            serde_json::from_str(r#"{"success":true,"orderID":"0xabc","status":"teleported"}"#)
+                .unwrap();
+        assert!(response.is_success());
+        assert_eq!(
+            response.outcome,
+            OrderOutcome::Unknown("teleported".to_string())
+        );
+
+        let response: PostOrderResponse =
+            serde_json::from_str(r#"{"success":true,"orderID":"0xabc"}"#).unwrap();
+        assert_eq!(response.order_id, "0xabc");
+        assert_eq!(response.outcome, OrderOutcome::Unknown(String::new()));
+    }
+
+    #[test]
+    fn test_decode_error_response() {
+        let err = decode_error_response(
+            400,
+            r#"{"error": "order 0xabc is invalid. Price (0.555) breaks minimum tick size rule: 0.01", "errorCode": "INVALID_ORDER_MIN_TICK_SIZE"}"#,
+            "Failed to post order",
+        );
+        assert!(matches!(
+            err,
+            PolyError::Order {
+                kind: OrderErrorKind::PriceConstraint,
+                error_code: Some(ref code),
+                ..
+            } if code == "INVALID_ORDER_MIN_TICK_SIZE"
+        ));
+
+        let err = decode_error_response(400, r#"{"error": "order crosses book"}"#, "ctx");
+        assert!(matches!(
+            err,
+            PolyError::Order {
+                kind: OrderErrorKind::PriceConstraint,
+                error_code: None,
+                ..
+            }
+        ));
+
+        let err = decode_error_response(
+            400,
+            r#"{"error": "allowance check unavailable for fok orders"}"#,
+            "ctx",
+        );
+        assert!(matches!(err, PolyError::Api { status: 400, .. }));
+
+        let err = decode_error_response(500, "upstream timeout", "Failed to get book");
+        assert!(matches!(
+            err,
+            PolyError::Api {
+                status: 500,
+                ref message,
+                error_code: None,
+            } if message == "Failed to get book: upstream timeout"
+        ));
+    }
+
+    #[test]
+    fn test_order_rejection_error() {
+        let err = order_rejection_error("not enough balance / allowance");
+        assert!(matches!(
+            err,
+            PolyError::Order {
+                kind: OrderErrorKind::InsufficientBalance,
+                error_code: Some(_),
+                ..
+            }
+        ));
+
+        let err = order_rejection_error("something new");
+        assert!(matches!(
+            err,
+            PolyError::Order {
+                kind: OrderErrorKind::ExecutionFailed,
+                error_code: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_rejection_kind() {
+        let outcome = OrderOutcome::Rejected {
+            reason: "order 0x1 is invalid. Duplicated.".to_string(),
+        };
+        assert_eq!(
+            outcome.rejection_kind(),
+            Some(OrderErrorKind::DuplicateOrder)
+        );
+        assert_eq!(OrderOutcome::Live.rejection_kind(), None);
+    }
+
     #[test]
     fn test_batch_decoder() {
         let mut decoder = BatchDecoder::new();
@@ -38,6 +38,8 @@ pub enum PolyError {
     Order {
         message: String,
         kind: OrderErrorKind,
+        /// CLOB error code when the error was reported by the server
+        error_code: Option<String>,
     },
 
     /// Market data errors
@@ -253,6 +255,7 @@ impl PolyError {
         Self::Order {
             message: message.into(),
             kind,
+            error_code: None,
         }
     }
 
@@ -392,9 +395,14 @@ impl Clone for PolyError {
                 message: message.clone(),
                 kind: kind.clone(),
             },
-            PolyError::Order { message, kind } => PolyError::Order {
+            PolyError::Order {
+                message,
+                kind,
+                error_code,
+            } => PolyError::Order {
                 message: message.clone(),
                 kind: kind.clone(),
+                error_code: error_code.clone(),
             },
             PolyError::MarketData { message, kind } => PolyError::MarketData {
                 message: message.clone(),
@@ -630,6 +630,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::assertions_on_constants)]
     fn test_fill_engine_advanced_creation() {
         // Test that we can create a fill engine with parameters
         let _engine = FillEngine::new(dec!(1.0), dec!(0.05), 50); // min_fill_size, max_slippage, fee_rate_bps
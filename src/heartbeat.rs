@@ -0,0 +1,594 @@
+//! Dead man's switch for resting orders
+//!
+//! A supervised task that cancels open orders when the application stops
+//! heartbeating or the user channel stays disconnected for too long. It can
+//! also keep the exchange's own heartbeat session alive, so orders are
+//! cancelled server-side even if this process dies outright.
+
+use crate::client::ClobClient;
+use crate::types::CancelOrdersResponse;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+
+/// Which orders are cancelled when the switch fires
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CancelScope {
+    /// `cancel_all`
+    All,
+    /// `cancel_market_orders` for each condition id
+    Markets(Vec<String>),
+    /// `cancel_market_orders` for each asset (token) id
+    Assets(Vec<String>),
+}
+
+/// Configuration for [`DeadMansSwitch`]
+#[derive(Debug, Clone)]
+pub struct DeadMansSwitchConfig {
+    /// Fire when no application heartbeat arrives for this long
+    pub heartbeat_timeout: Duration,
+    /// Fire when the user channel has been disconnected for this long
+    pub disconnect_threshold: Option<Duration>,
+    /// How often the switch checks its inputs
+    pub check_interval: Duration,
+    /// Also keep a server-side heartbeat session alive while healthy
+    pub server_heartbeat: bool,
+    /// How often the server-side heartbeat is sent. A request that takes
+    /// longer than this is abandoned.
+    pub server_heartbeat_interval: Duration,
+    pub scope: CancelScope,
+}
+
+impl DeadMansSwitchConfig {
+    pub fn new(heartbeat_timeout: Duration) -> Self {
+        Self {
+            heartbeat_timeout,
+            disconnect_threshold: None,
+            check_interval: (heartbeat_timeout / 4).max(Duration::from_millis(10)),
+            server_heartbeat: false,
+            server_heartbeat_interval: Duration::from_secs(5),
+            scope: CancelScope::All,
+        }
+    }
+
+    pub fn with_disconnect_threshold(mut self, threshold: Duration) -> Self {
+        self.disconnect_threshold = Some(threshold);
+        self
+    }
+
+    pub fn with_check_interval(mut self, interval: Duration) -> Self {
+        self.check_interval = interval;
+        self
+    }
+
+    pub fn with_server_heartbeat(mut self, enabled: bool) -> Self {
+        self.server_heartbeat = enabled;
+        self
+    }
+
+    pub fn with_server_heartbeat_interval(mut self, interval: Duration) -> Self {
+        self.server_heartbeat_interval = interval;
+        self
+    }
+
+    pub fn with_scope(mut self, scope: CancelScope) -> Self {
+        self.scope = scope;
+        self
+    }
+}
+
+/// Why the switch fired
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trigger {
+    HeartbeatLapsed { silent_for: Duration },
+    Disconnected { down_for: Duration },
+}
+
+/// Everything the switch reports through its event hook
+#[derive(Debug, Clone)]
+pub enum DeadMansSwitchEvent {
+    Triggered(Trigger),
+    Cancelled {
+        trigger: Trigger,
+        response: CancelOrdersResponse,
+    },
+    /// Some cancel requests failed; the switch stays armed and retries
+    CancelFailed {
+        trigger: Trigger,
+        /// Merged responses of the cancel requests that went through
+        response: CancelOrdersResponse,
+        /// Error per condition or asset id whose cancel request failed
+        /// (`"all"` for [`CancelScope::All`])
+        failed: HashMap<String, String>,
+    },
+    ServerHeartbeatFailed {
+        error: String,
+    },
+    /// Inputs are healthy again after the switch fired
+    Rearmed,
+}
+
+/// Callback invoked for every [`DeadMansSwitchEvent`]
+pub type EventHook = Arc<dyn Fn(&DeadMansSwitchEvent) + Send + Sync>;
+
+#[derive(Debug)]
+struct SwitchState {
+    last_heartbeat: Instant,
+    disconnected_since: Option<Instant>,
+    tripped: bool,
+}
+
+/// Cheap, cloneable handle used to feed the switch
+#[derive(Debug, Clone)]
+pub struct SwitchHandle {
+    state: Arc<Mutex<SwitchState>>,
+}
+
+impl SwitchHandle {
+    fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(SwitchState {
+                last_heartbeat: Instant::now(),
+                disconnected_since: None,
+                tripped: false,
+            })),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, SwitchState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Signal that the application is alive
+    pub fn heartbeat(&self) {
+        self.lock().last_heartbeat = Instant::now();
+    }
+
+    /// Record the state of the user channel connection. Wired up
+    /// automatically by [`WssUserClient::with_switch_handle`](crate::wss::WssUserClient::with_switch_handle).
+    pub fn set_connected(&self, connected: bool) {
+        let mut state = self.lock();
+        if connected {
+            state.disconnected_since = None;
+        } else if state.disconnected_since.is_none() {
+            state.disconnected_since = Some(Instant::now());
+        }
+    }
+
+    fn pending_trigger(&self, config: &DeadMansSwitchConfig) -> Option<Trigger> {
+        let state = self.lock();
+        let silent_for = state.last_heartbeat.elapsed();
+        if silent_for > config.heartbeat_timeout {
+            return Some(Trigger::HeartbeatLapsed { silent_for });
+        }
+        match (state.disconnected_since, config.disconnect_threshold) {
+            (Some(since), Some(threshold)) if since.elapsed() > threshold => {
+                Some(Trigger::Disconnected {
+                    down_for: since.elapsed(),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Supervised heartbeat task that cancels orders when its inputs go quiet.
+///
+/// The task stops when the switch is dropped or [`shutdown`](Self::shutdown)
+/// is called.
+///
+/// ```no_run
+/// # use polysqueeze::client::ClobClient;
+/// # use polysqueeze::heartbeat::{DeadMansSwitch, DeadMansSwitchConfig};
+/// # use std::{sync::Arc, time::Duration};
+/// # async fn run(client: Arc<ClobClient>) {
+/// let config = DeadMansSwitchConfig::new(Duration::from_secs(5))
+///     .with_disconnect_threshold(Duration::from_secs(10));
+/// let switch = DeadMansSwitch::spawn(client, config, None);
+/// loop {
+///     // ... quote ...
+///     switch.heartbeat();
+/// }
+/// # }
+/// ```
+pub struct DeadMansSwitch {
+    handle: SwitchHandle,
+    task: JoinHandle<()>,
+    server_task: Option<JoinHandle<()>>,
+}
+
+impl DeadMansSwitch {
+    pub fn spawn(
+        client: Arc<ClobClient>,
+        config: DeadMansSwitchConfig,
+        hook: Option<EventHook>,
+    ) -> Self {
+        let handle = SwitchHandle::new();
+        // The server heartbeat runs on its own so a slow request never
+        // holds up the check and cancel path
+        let server_task = config.server_heartbeat.then(|| {
+            tokio::spawn(keep_server_session(
+                client.clone(),
+                config.clone(),
+                handle.clone(),
+                hook.clone(),
+            ))
+        });
+        let task = tokio::spawn(supervise(client, config, handle.clone(), hook));
+        Self {
+            handle,
+            task,
+            server_task,
+        }
+    }
+
+    /// Handle for feeding heartbeats and connection state from elsewhere
+    pub fn handle(&self) -> SwitchHandle {
+        self.handle.clone()
+    }
+
+    /// Signal that the application is alive
+    pub fn heartbeat(&self) {
+        self.handle.heartbeat();
+    }
+
+    /// Stop supervising without cancelling anything
+    pub fn shutdown(self) {}
+}
+
+impl Drop for DeadMansSwitch {
+    fn drop(&mut self) {
+        self.task.abort();
+        if let Some(task) = &self.server_task {
+            task.abort();
+        }
+    }
+}
+
+fn emit(hook: &Option<EventHook>, event: DeadMansSwitchEvent) {
+    if let Some(hook) = hook {
+        hook(&event);
+    }
+}
+
+async fn supervise(
+    client: Arc<ClobClient>,
+    config: DeadMansSwitchConfig,
+    handle: SwitchHandle,
+    hook: Option<EventHook>,
+) {
+    let mut ticker = tokio::time::interval(config.check_interval);
+
+    loop {
+        ticker.tick().await;
+        let tripped = handle.lock().tripped;
+
+        let Some(trigger) = handle.pending_trigger(&config) else {
+            if tripped {
+                handle.lock().tripped = false;
+                emit(&hook, DeadMansSwitchEvent::Rearmed);
+            }
+            continue;
+        };
+
+        if tripped {
+            continue;
+        }
+        emit(&hook, DeadMansSwitchEvent::Triggered(trigger));
+        tracing::warn!(?trigger, "dead man's switch fired, cancelling orders");
+
+        // A failed cancel leaves the switch armed so the next check retries
+        let (response, failed) = cancel_scope(&client, &config.scope).await;
+        if failed.is_empty() {
+            handle.lock().tripped = true;
+            emit(&hook, DeadMansSwitchEvent::Cancelled { trigger, response });
+        } else {
+            emit(
+                &hook,
+                DeadMansSwitchEvent::CancelFailed {
+                    trigger,
+                    response,
+                    failed,
+                },
+            );
+        }
+    }
+}
+
+/// Keep the exchange's heartbeat session alive while the switch is healthy.
+///
+/// The session is dropped whenever the switch fires or a heartbeat fails, so
+/// the next healthy tick starts a fresh one instead of reusing an expired id.
+async fn keep_server_session(
+    client: Arc<ClobClient>,
+    config: DeadMansSwitchConfig,
+    handle: SwitchHandle,
+    hook: Option<EventHook>,
+) {
+    let mut heartbeat_id: Option<String> = None;
+    let mut ticker = tokio::time::interval(config.server_heartbeat_interval);
+
+    loop {
+        ticker.tick().await;
+        if handle.lock().tripped || handle.pending_trigger(&config).is_some() {
+            heartbeat_id = None;
+            continue;
+        }
+
+        let request = client.post_heartbeat(heartbeat_id.as_deref());
+        let error = match tokio::time::timeout(config.server_heartbeat_interval, request).await {
+            Ok(Ok(id)) => {
+                heartbeat_id = Some(id);
+                continue;
+            }
+            Ok(Err(err)) => err.to_string(),
+            Err(_) => "Server heartbeat timed out".to_string(),
+        };
+        heartbeat_id = None;
+        emit(&hook, DeadMansSwitchEvent::ServerHeartbeatFailed { error });
+    }
+}
+
+/// Cancel every market or asset in `scope`, returning the merged responses
+/// of the requests that succeeded and the error of each one that failed
+async fn cancel_scope(
+    client: &ClobClient,
+    scope: &CancelScope,
+) -> (CancelOrdersResponse, HashMap<String, String>) {
+    let results = match scope {
+        CancelScope::All => vec![("all".to_string(), client.cancel_all().await)],
+        CancelScope::Markets(markets) => {
+            let mut results = Vec::with_capacity(markets.len());
+            for market in markets {
+                let result = client.cancel_market_orders(Some(market), None).await;
+                results.push((market.clone(), result));
+            }
+            results
+        }
+        CancelScope::Assets(assets) => {
+            let mut results = Vec::with_capacity(assets.len());
+            for asset in assets {
+                let result = client.cancel_market_orders(None, Some(asset)).await;
+                results.push((asset.clone(), result));
+            }
+            results
+        }
+    };
+
+    let mut combined = CancelOrdersResponse::default();
+    let mut failed = HashMap::new();
+    for (id, result) in results {
+        match result {
+            Ok(response) => {
+                combined.canceled.extend(response.canceled);
+                combined.not_canceled.extend(response.not_canceled);
+            }
+            Err(err) => {
+                failed.insert(id, err.to_string());
+            }
+        }
+    }
+    (combined, failed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ApiCredentials;
+    use mockito::{Matcher, Server};
+
+    fn create_client(base_url: &str) -> Arc<ClobClient> {
+        Arc::new(ClobClient::with_l2_headers(
+            base_url,
+            "0x1234567890123456789012345678901234567890123456789012345678901234",
+            137,
+            ApiCredentials {
+                api_key: "test_key".to_string(),
+                secret: "dGVzdF9zZWNyZXQ=".to_string(),
+                passphrase: "test_passphrase".to_string(),
+            },
+        ))
+    }
+
+    fn recording_hook() -> (EventHook, Arc<Mutex<Vec<DeadMansSwitchEvent>>>) {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let hook: EventHook = Arc::new(move |event| sink.lock().unwrap().push(event.clone()));
+        (hook, events)
+    }
+
+    #[tokio::test]
+    async fn test_heartbeat_lapse_cancels_once() {
+        let mut server = Server::new_async().await;
+        let cancel_mock = server
+            .mock("DELETE", "/cancel-all")
+            .with_status(200)
+            .with_body(r#"{"canceled": ["0x1"], "not_canceled": {}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let (hook, events) = recording_hook();
+        let config = DeadMansSwitchConfig::new(Duration::from_millis(50))
+            .with_check_interval(Duration::from_millis(10));
+        let switch = DeadMansSwitch::spawn(create_client(&server.url()), config, Some(hook));
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        cancel_mock.assert_async().await;
+
+        let events = events.lock().unwrap().clone();
+        assert!(matches!(
+            events[0],
+            DeadMansSwitchEvent::Triggered(Trigger::HeartbeatLapsed { .. })
+        ));
+        match &events[1] {
+            DeadMansSwitchEvent::Cancelled { response, .. } => {
+                assert!(response.is_canceled("0x1"))
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+        assert_eq!(events.len(), 2);
+        drop(switch);
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_cancels_scoped_markets() {
+        let mut server = Server::new_async().await;
+        let cancel_mock = server
+            .mock("DELETE", "/cancel-market-orders")
+            .match_body(Matcher::PartialJson(serde_json::json!({"market": "0xabc"})))
+            .with_status(200)
+            .with_body(r#"{"canceled": [], "not_canceled": {}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let (hook, events) = recording_hook();
+        let config = DeadMansSwitchConfig::new(Duration::from_secs(60))
+            .with_disconnect_threshold(Duration::from_millis(30))
+            .with_check_interval(Duration::from_millis(10))
+            .with_scope(CancelScope::Markets(vec!["0xabc".into()]));
+        let switch = DeadMansSwitch::spawn(create_client(&server.url()), config, Some(hook));
+
+        switch.handle().set_connected(false);
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        cancel_mock.assert_async().await;
+
+        switch.handle().set_connected(true);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let events = events.lock().unwrap().clone();
+        assert!(matches!(
+            events[0],
+            DeadMansSwitchEvent::Triggered(Trigger::Disconnected { .. })
+        ));
+        assert!(matches!(events.last(), Some(DeadMansSwitchEvent::Rearmed)));
+    }
+
+    #[tokio::test]
+    async fn test_server_heartbeat_reuses_session_id() {
+        let mut server = Server::new_async().await;
+        let first = server
+            .mock("POST", "/v1/heartbeats")
+            .match_body(Matcher::Json(serde_json::json!({"heartbeat_id": null})))
+            .with_status(200)
+            .with_body(r#"{"heartbeat_id": "hb-1"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let next = server
+            .mock("POST", "/v1/heartbeats")
+            .match_body(Matcher::Json(serde_json::json!({"heartbeat_id": "hb-1"})))
+            .with_status(200)
+            .with_body(r#"{"heartbeat_id": "hb-1"}"#)
+            .expect_at_least(1)
+            .create_async()
+            .await;
+
+        let config = DeadMansSwitchConfig::new(Duration::from_secs(60))
+            .with_server_heartbeat(true)
+            .with_server_heartbeat_interval(Duration::from_millis(10));
+        let switch = DeadMansSwitch::spawn(create_client(&server.url()), config, None);
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        switch.shutdown();
+        first.assert_async().await;
+        next.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_failed_server_heartbeat_starts_new_session() {
+        let mut server = Server::new_async().await;
+        let start = server
+            .mock("POST", "/v1/heartbeats")
+            .match_body(Matcher::Json(serde_json::json!({"heartbeat_id": null})))
+            .with_status(200)
+            .with_body(r#"{"heartbeat_id": "hb-1"}"#)
+            .expect_at_least(2)
+            .create_async()
+            .await;
+        let expired = server
+            .mock("POST", "/v1/heartbeats")
+            .match_body(Matcher::Json(serde_json::json!({"heartbeat_id": "hb-1"})))
+            .with_status(400)
+            .with_body(r#"{"error": "heartbeat expired"}"#)
+            .expect_at_least(1)
+            .create_async()
+            .await;
+
+        let (hook, events) = recording_hook();
+        let config = DeadMansSwitchConfig::new(Duration::from_secs(60))
+            .with_server_heartbeat(true)
+            .with_server_heartbeat_interval(Duration::from_millis(10));
+        let switch = DeadMansSwitch::spawn(create_client(&server.url()), config, Some(hook));
+
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        switch.shutdown();
+        start.assert_async().await;
+        expired.assert_async().await;
+        assert!(
+            events
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|event| matches!(event, DeadMansSwitchEvent::ServerHeartbeatFailed { .. }))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cancel_tries_every_market() {
+        let mut server = Server::new_async().await;
+        let first = server
+            .mock("DELETE", "/cancel-market-orders")
+            .match_body(Matcher::PartialJson(serde_json::json!({"market": "0xa"})))
+            .with_status(200)
+            .with_body(r#"{"canceled": ["0x1"], "not_canceled": {}}"#)
+            .expect_at_least(1)
+            .create_async()
+            .await;
+        let failing = server
+            .mock("DELETE", "/cancel-market-orders")
+            .match_body(Matcher::PartialJson(serde_json::json!({"market": "0xb"})))
+            .with_status(400)
+            .with_body(r#"{"error": "bad market"}"#)
+            .expect_at_least(1)
+            .create_async()
+            .await;
+        let last = server
+            .mock("DELETE", "/cancel-market-orders")
+            .match_body(Matcher::PartialJson(serde_json::json!({"market": "0xc"})))
+            .with_status(200)
+            .with_body(r#"{"canceled": ["0x3"], "not_canceled": {}}"#)
+            .expect_at_least(1)
+            .create_async()
+            .await;
+
+        let (hook, events) = recording_hook();
+        let config = DeadMansSwitchConfig::new(Duration::from_millis(20))
+            .with_check_interval(Duration::from_millis(10))
+            .with_scope(CancelScope::Markets(vec![
+                "0xa".into(),
+                "0xb".into(),
+                "0xc".into(),
+            ]));
+        let switch = DeadMansSwitch::spawn(create_client(&server.url()), config, Some(hook));
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        drop(switch);
+        first.assert_async().await;
+        failing.assert_async().await;
+        last.assert_async().await;
+
+        let events = events.lock().unwrap().clone();
+        match &events[1] {
+            DeadMansSwitchEvent::CancelFailed {
+                response, failed, ..
+            } => {
+                assert!(response.is_canceled("0x1") && response.is_canceled("0x3"));
+                assert_eq!(failed.keys().collect::<Vec<_>>(), vec!["0xb"]);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,570 @@
+//! Encrypted key material
+//!
+//! Loads Ethereum V3 JSON keystores (scrypt or pbkdf2 with AES-128-CTR) and
+//! keeps derived [`ApiCredentials`] in a password-encrypted file, so neither
+//! private keys nor API secrets have to sit in plaintext on disk or in the
+//! environment. Decrypted bytes live in [`Zeroizing`] buffers.
+
+use crate::client::ClobClient;
+use crate::errors::{PolyError, Result};
+use crate::types::ApiCredentials;
+use aes::Aes128;
+use alloy_primitives::{Address, B256, Keccak256};
+use alloy_signer_local::PrivateKeySigner;
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+type Aes128Ctr = ctr::Ctr128BE<Aes128>;
+
+const CIPHER: &str = "aes-128-ctr";
+const DKLEN: usize = 32;
+// Upper bounds on untrusted KDF costs, at the standard V3 settings
+const MAX_SCRYPT_LOG_N: u32 = 18;
+const MAX_SCRYPT_R: u32 = 8;
+const MAX_SCRYPT_P: u32 = 16;
+const MAX_PBKDF2_C: u32 = 1_000_000;
+
+/// Key derivation settings for newly encrypted data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KdfParams {
+    Scrypt { log_n: u8, r: u32, p: u32 },
+    Pbkdf2 { c: u32 },
+}
+
+impl Default for KdfParams {
+    /// The standard V3 scrypt cost (n = 2^18, r = 8, p = 1)
+    fn default() -> Self {
+        KdfParams::Scrypt {
+            log_n: 18,
+            r: 8,
+            p: 1,
+        }
+    }
+}
+
+impl KdfParams {
+    /// Cheaper scrypt cost (n = 2^12, r = 8, p = 6), as used by geth's
+    /// `--lightkdf`
+    pub fn light() -> Self {
+        KdfParams::Scrypt {
+            log_n: 12,
+            r: 8,
+            p: 6,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kdf", content = "kdfparams", rename_all = "lowercase")]
+enum Kdf {
+    Scrypt {
+        dklen: usize,
+        n: u64,
+        r: u32,
+        p: u32,
+        salt: String,
+    },
+    Pbkdf2 {
+        dklen: usize,
+        c: u32,
+        prf: String,
+        salt: String,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CipherParams {
+    iv: String,
+}
+
+/// Ciphertext and MAC under an already derived key
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Sealed {
+    cipher: String,
+    cipherparams: CipherParams,
+    ciphertext: String,
+    mac: String,
+}
+
+/// The `crypto` section of a V3 keystore
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CryptoJson {
+    #[serde(flatten)]
+    sealed: Sealed,
+    #[serde(flatten)]
+    kdf: Kdf,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KeystoreJson {
+    #[serde(alias = "Crypto")]
+    crypto: CryptoJson,
+    id: String,
+    version: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+}
+
+fn decode_hex(field: &str, value: &str) -> Result<Vec<u8>> {
+    alloy_primitives::hex::decode(value)
+        .map_err(|e| PolyError::parse(format!("Invalid keystore {}: {}", field, e), None))
+}
+
+fn derive_key(kdf: &Kdf, password: &[u8]) -> Result<Zeroizing<Vec<u8>>> {
+    let mut key = Zeroizing::new(vec![0u8; DKLEN]);
+    match kdf {
+        Kdf::Scrypt {
+            dklen,
+            n,
+            r,
+            p,
+            salt,
+        } => {
+            if *dklen != DKLEN || !n.is_power_of_two() {
+                return Err(PolyError::validation("Unsupported scrypt parameters"));
+            }
+            if n.trailing_zeros() > MAX_SCRYPT_LOG_N || *r > MAX_SCRYPT_R || *p > MAX_SCRYPT_P {
+                return Err(PolyError::validation(format!(
+                    "scrypt cost too high (n = {}, r = {}, p = {})",
+                    n, r, p
+                )));
+            }
+            let params = scrypt::Params::new(n.trailing_zeros() as u8, *r, *p, DKLEN)
+                .map_err(|e| PolyError::validation(format!("Invalid scrypt parameters: {}", e)))?;
+            scrypt::scrypt(password, &decode_hex("salt", salt)?, &params, &mut key)
+                .map_err(|e| PolyError::crypto(format!("scrypt failed: {}", e)))?;
+        }
+        Kdf::Pbkdf2 {
+            dklen,
+            c,
+            prf,
+            salt,
+        } => {
+            if *dklen != DKLEN || prf != "hmac-sha256" || *c == 0 {
+                return Err(PolyError::validation("Unsupported pbkdf2 parameters"));
+            }
+            if *c > MAX_PBKDF2_C {
+                return Err(PolyError::validation(format!(
+                    "pbkdf2 cost too high (c = {})",
+                    c
+                )));
+            }
+            pbkdf2::pbkdf2_hmac::<Sha256>(password, &decode_hex("salt", salt)?, *c, &mut key);
+        }
+    }
+    Ok(key)
+}
+
+fn mac(key: &[u8], ciphertext: &[u8]) -> B256 {
+    let mut hasher = Keccak256::new();
+    hasher.update(&key[16..32]);
+    hasher.update(ciphertext);
+    hasher.finalize()
+}
+
+fn mac_matches(key: &[u8], ciphertext: &[u8], expected: &str) -> Result<bool> {
+    let expected = decode_hex("mac", expected)?;
+    Ok(mac(key, ciphertext).as_slice().ct_eq(&expected).into())
+}
+
+fn apply_cipher(key: &[u8], iv: &[u8], data: &mut [u8]) -> Result<()> {
+    let mut cipher = Aes128Ctr::new_from_slices(&key[..16], iv)
+        .map_err(|_| PolyError::validation("Invalid keystore IV length"))?;
+    cipher.apply_keystream(data);
+    Ok(())
+}
+
+fn new_kdf(params: KdfParams) -> Kdf {
+    let salt: [u8; 32] = rand::rng().random();
+    let salt = alloy_primitives::hex::encode(salt);
+    match params {
+        KdfParams::Scrypt { log_n, r, p } => Kdf::Scrypt {
+            dklen: DKLEN,
+            n: 1u64 << log_n,
+            r,
+            p,
+            salt,
+        },
+        KdfParams::Pbkdf2 { c } => Kdf::Pbkdf2 {
+            dklen: DKLEN,
+            c,
+            prf: "hmac-sha256".to_string(),
+            salt,
+        },
+    }
+}
+
+fn seal(key: &[u8], plaintext: &[u8]) -> Result<Sealed> {
+    let iv: [u8; 16] = rand::rng().random();
+    let mut ciphertext = plaintext.to_vec();
+    apply_cipher(key, &iv, &mut ciphertext)?;
+
+    Ok(Sealed {
+        cipher: CIPHER.to_string(),
+        cipherparams: CipherParams {
+            iv: alloy_primitives::hex::encode(iv),
+        },
+        mac: alloy_primitives::hex::encode(mac(key, &ciphertext)),
+        ciphertext: alloy_primitives::hex::encode(ciphertext),
+    })
+}
+
+fn unseal(key: &[u8], sealed: &Sealed) -> Result<Zeroizing<Vec<u8>>> {
+    if sealed.cipher != CIPHER {
+        return Err(PolyError::validation(format!(
+            "Unsupported keystore cipher: {}",
+            sealed.cipher
+        )));
+    }
+    let ciphertext = decode_hex("ciphertext", &sealed.ciphertext)?;
+    if !mac_matches(key, &ciphertext, &sealed.mac)? {
+        return Err(PolyError::auth("Wrong keystore password or corrupted data"));
+    }
+
+    let mut plaintext = Zeroizing::new(ciphertext);
+    apply_cipher(
+        key,
+        &decode_hex("iv", &sealed.cipherparams.iv)?,
+        &mut plaintext,
+    )?;
+    Ok(plaintext)
+}
+
+fn encrypt(plaintext: &[u8], password: &str, params: KdfParams) -> Result<CryptoJson> {
+    let kdf = new_kdf(params);
+    let key = derive_key(&kdf, password.as_bytes())?;
+    Ok(CryptoJson {
+        sealed: seal(&key, plaintext)?,
+        kdf,
+    })
+}
+
+fn decrypt(crypto: &CryptoJson, password: &str) -> Result<Zeroizing<Vec<u8>>> {
+    let key = derive_key(&crypto.kdf, password.as_bytes())?;
+    unseal(&key, &crypto.sealed)
+}
+
+/// Decrypt a V3 JSON keystore into a signer.
+///
+/// When the keystore records an address it must match the decrypted key.
+pub fn decrypt_keystore(json: &str, password: &str) -> Result<PrivateKeySigner> {
+    let keystore: KeystoreJson = serde_json::from_str(json)
+        .map_err(|e| PolyError::parse(format!("Invalid keystore JSON: {}", e), None))?;
+    if keystore.version != 3 {
+        return Err(PolyError::validation(format!(
+            "Unsupported keystore version: {}",
+            keystore.version
+        )));
+    }
+
+    let secret = decrypt(&keystore.crypto, password)?;
+    let signer = PrivateKeySigner::from_slice(&secret)
+        .map_err(|e| PolyError::crypto(format!("Invalid private key in keystore: {}", e)))?;
+
+    if let Some(address) = &keystore.address {
+        let expected = decode_hex("address", address)?;
+        if expected.as_slice() != signer.address().as_slice() {
+            return Err(PolyError::validation(
+                "Keystore address does not match its key",
+            ));
+        }
+    }
+    Ok(signer)
+}
+
+/// Read and decrypt a V3 JSON keystore file
+pub fn load_keystore(path: impl AsRef<Path>, password: &str) -> Result<PrivateKeySigner> {
+    let json = std::fs::read_to_string(path.as_ref()).map_err(|e| {
+        PolyError::config(format!(
+            "Failed to read keystore {}: {}",
+            path.as_ref().display(),
+            e
+        ))
+    })?;
+    decrypt_keystore(&json, password)
+}
+
+/// Encrypt a signer's key as a V3 JSON keystore
+pub fn encrypt_keystore(
+    signer: &PrivateKeySigner,
+    password: &str,
+    params: KdfParams,
+) -> Result<String> {
+    let secret = Zeroizing::new(signer.to_bytes().0);
+    let keystore = KeystoreJson {
+        crypto: encrypt(secret.as_slice(), password, params)?,
+        id: uuid::Uuid::new_v4().to_string(),
+        version: 3,
+        address: Some(alloy_primitives::hex::encode(signer.address())),
+    };
+    serde_json::to_string(&keystore)
+        .map_err(|e| PolyError::parse(format!("Failed to serialize keystore: {}", e), None))
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CredentialFile {
+    version: u8,
+    /// Derives the one key every entry is sealed with
+    #[serde(flatten)]
+    kdf: Kdf,
+    /// MAC of an empty ciphertext under the store key, so a wrong password
+    /// is rejected on open
+    check: String,
+    /// Keyed by `<lowercase address>:<chain id>`
+    entries: BTreeMap<String, Sealed>,
+}
+
+/// Password-encrypted file of [`ApiCredentials`] keyed by address and chain.
+///
+/// The store key is derived once when the store is opened, and each entry
+/// is sealed under it with the same cipher and MAC as a V3 keystore. Writes
+/// go through a temporary file and, on Unix, the file is only readable by
+/// its owner.
+pub struct CredentialStore {
+    path: PathBuf,
+    key: Zeroizing<Vec<u8>>,
+    file: CredentialFile,
+}
+
+impl CredentialStore {
+    /// Open the store at `path`, starting empty if the file does not exist.
+    ///
+    /// Fails with [`PolyError::Auth`] when `password` is not the one the
+    /// store was created with.
+    pub fn open(path: impl Into<PathBuf>, password: &str) -> Result<Self> {
+        Self::open_with_kdf(path, password, KdfParams::default())
+    }
+
+    /// Open the store at `path`, deriving its key with `params` if the file
+    /// does not exist yet. An existing store keeps its own settings.
+    pub fn open_with_kdf(
+        path: impl Into<PathBuf>,
+        password: &str,
+        params: KdfParams,
+    ) -> Result<Self> {
+        let path = path.into();
+        let (key, file) = match std::fs::read_to_string(&path) {
+            Ok(json) => {
+                let file: CredentialFile = serde_json::from_str(&json).map_err(|e| {
+                    PolyError::parse(format!("Invalid credential store: {}", e), None)
+                })?;
+                let key = derive_key(&file.kdf, password.as_bytes())?;
+                if !mac_matches(&key, &[], &file.check)? {
+                    return Err(PolyError::auth("Wrong credential store password"));
+                }
+                (key, file)
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                let kdf = new_kdf(params);
+                let key = derive_key(&kdf, password.as_bytes())?;
+                let file = CredentialFile {
+                    version: 1,
+                    check: alloy_primitives::hex::encode(mac(&key, &[])),
+                    kdf,
+                    entries: BTreeMap::new(),
+                };
+                (key, file)
+            }
+            Err(e) => {
+                return Err(PolyError::config(format!(
+                    "Failed to read credential store {}: {}",
+                    path.display(),
+                    e
+                )));
+            }
+        };
+
+        Ok(Self { path, key, file })
+    }
+
+    fn key(address: Address, chain_id: u64) -> String {
+        format!("{:x}:{}", address, chain_id)
+    }
+
+    /// Decrypt the credentials stored for `address` on `chain_id`
+    pub fn get(&self, address: Address, chain_id: u64) -> Result<Option<ApiCredentials>> {
+        let Some(entry) = self.file.entries.get(&Self::key(address, chain_id)) else {
+            return Ok(None);
+        };
+        let plaintext = unseal(&self.key, entry)?;
+        serde_json::from_slice(&plaintext)
+            .map(Some)
+            .map_err(|e| PolyError::parse(format!("Invalid stored credentials: {}", e), None))
+    }
+
+    /// Encrypt and persist credentials for `address` on `chain_id`
+    pub fn insert(
+        &mut self,
+        address: Address,
+        chain_id: u64,
+        creds: &ApiCredentials,
+    ) -> Result<()> {
+        let plaintext = Zeroizing::new(serde_json::to_vec(creds).map_err(|e| {
+            PolyError::parse(format!("Failed to serialize credentials: {}", e), None)
+        })?);
+        let entry = seal(&self.key, &plaintext)?;
+        self.file
+            .entries
+            .insert(Self::key(address, chain_id), entry);
+        self.save()
+    }
+
+    /// Forget the credentials for `address` on `chain_id`
+    pub fn remove(&mut self, address: Address, chain_id: u64) -> Result<bool> {
+        let removed = self
+            .file
+            .entries
+            .remove(&Self::key(address, chain_id))
+            .is_some();
+        if removed {
+            self.save()?;
+        }
+        Ok(removed)
+    }
+
+    /// Stored credentials for the client's signer, or new ones from
+    /// [`ClobClient::create_or_derive_api_key`], which are then stored
+    pub async fn get_or_derive(&mut self, client: &ClobClient) -> Result<ApiCredentials> {
+        let address = client
+            .address()
+            .ok_or_else(|| PolyError::config("Signer not configured"))?;
+        if let Some(creds) = self.get(address, client.chain_id())? {
+            return Ok(creds);
+        }
+
+        let creds = client.create_or_derive_api_key(None).await?;
+        self.insert(address, client.chain_id(), &creds)?;
+        Ok(creds)
+    }
+
+    fn save(&self) -> Result<()> {
+        let json = serde_json::to_vec_pretty(&self.file).map_err(|e| {
+            PolyError::parse(format!("Failed to serialize credential store: {}", e), None)
+        })?;
+        let tmp = self.path.with_extension("tmp");
+        let io_error = |e: std::io::Error| {
+            PolyError::config(format!(
+                "Failed to write credential store {}: {}",
+                self.path.display(),
+                e
+            ))
+        };
+
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options.open(&tmp).map_err(io_error)?;
+        std::io::Write::write_all(&mut file, &json).map_err(io_error)?;
+        file.sync_all().map_err(io_error)?;
+        std::fs::rename(&tmp, &self.path).map_err(io_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vector from the Web3 Secret Storage definition
+    const PBKDF2_KEYSTORE: &str = r#"{
+        "crypto": {
+            "cipher": "aes-128-ctr",
+            "cipherparams": {"iv": "6087dab2f9fdbbfaddc31a909735c1e6"},
+            "ciphertext": "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+            "kdf": "pbkdf2",
+            "kdfparams": {
+                "c": 262144,
+                "dklen": 32,
+                "prf": "hmac-sha256",
+                "salt": "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+            },
+            "mac": "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+        },
+        "id": "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version": 3
+    }"#;
+
+    #[test]
+    fn test_decrypt_pbkdf2_keystore() {
+        let signer = decrypt_keystore(PBKDF2_KEYSTORE, "testpassword").unwrap();
+        assert_eq!(
+            alloy_primitives::hex::encode(signer.to_bytes()),
+            "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d"
+        );
+        assert!(matches!(
+            decrypt_keystore(PBKDF2_KEYSTORE, "wrong"),
+            Err(PolyError::Auth { .. })
+        ));
+    }
+
+    #[test]
+    fn test_rejects_excessive_kdf_costs() {
+        let scrypt = PBKDF2_KEYSTORE
+            .replace(r#""kdf": "pbkdf2""#, r#""kdf": "scrypt""#)
+            .replace(r#""c": 262144,"#, r#""n": 1073741824, "r": 8, "p": 1,"#)
+            .replace(r#""prf": "hmac-sha256","#, "");
+        let pbkdf2 = PBKDF2_KEYSTORE.replace(r#""c": 262144"#, r#""c": 4294967295"#);
+
+        for json in [scrypt, pbkdf2] {
+            assert!(matches!(
+                decrypt_keystore(&json, "testpassword"),
+                Err(PolyError::Validation { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_scrypt_keystore_roundtrip() {
+        let signer = PrivateKeySigner::random();
+        let json = encrypt_keystore(&signer, "hunter2", KdfParams::light()).unwrap();
+        assert!(json.contains(r#""kdf":"scrypt""#));
+
+        let decrypted = decrypt_keystore(&json, "hunter2").unwrap();
+        assert_eq!(decrypted.address(), signer.address());
+    }
+
+    #[test]
+    fn test_credential_store_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("polysqueeze-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("creds.json");
+        let address = Address::repeat_byte(7);
+        let creds = ApiCredentials {
+            api_key: "key".to_string(),
+            secret: "c2VjcmV0".to_string(),
+            passphrase: "pass".to_string(),
+        };
+
+        let mut store = CredentialStore::open_with_kdf(&path, "pw", KdfParams::light()).unwrap();
+        assert!(store.get(address, 137).unwrap().is_none());
+        store.insert(address, 137, &creds).unwrap();
+
+        let raw = std::fs::read_to_string(&path).unwrap();
+        assert!(!raw.contains("c2VjcmV0") && !raw.contains("pass\""));
+        assert_eq!(raw.matches("kdfparams").count(), 1);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        let reopened = CredentialStore::open(&path, "pw").unwrap();
+        let loaded = reopened.get(address, 137).unwrap().unwrap();
+        assert_eq!(loaded.api_key, "key");
+        assert_eq!(loaded.secret, "c2VjcmV0");
+        assert!(reopened.get(address, 80002).unwrap().is_none());
+        assert!(matches!(
+            CredentialStore::open(&path, "other"),
+            Err(PolyError::Auth { .. })
+        ));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
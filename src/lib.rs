@@ -3,20 +3,27 @@
 //! Use it to authenticate, build signed orders, stream live book data, or query
 //! historical fills and markets.
 
+pub mod accounts;
 pub mod auth;
 pub mod book;
+pub mod cache;
 pub mod client;
 pub mod config;
 pub mod decode;
 pub mod errors;
 pub mod fill;
+pub mod heartbeat;
+pub mod keystore;
 pub mod orders;
+pub mod rewards;
 pub mod types;
 pub mod utils;
+pub mod wallet;
 pub mod ws;
 pub mod wss;
 
-pub use crate::client::{ClobClient, CreateOrderOptions, DataApiClient, MarketClient, OrderArgs, PolyClient};
+pub use crate::auth::PolySigner;
+pub use crate::client::{ClobClient, ClobClientBuilder, CreateOrderOptions, DataApiClient, MarketClient, OrderArgs, PolyClient};
 pub use crate::errors::{PolyError, Result};
 pub use crate::types::{ApiCredentials, SignedOrderRequest};
 pub use crate::wss::{WssMarketClient, WssMarketEvent, WssUserClient, WssUserEvent};
@@ -3,23 +3,26 @@
 //! This module handles the complex process of creating and signing orders
 //! for the Polymarket CLOB, including EIP-712 signature generation.
 
-use crate::auth::sign_order_message;
+use crate::auth::{Order, PolySigner, order_domain, sign_order_message};
 use crate::client::OrderArgs;
+use crate::config::NetworkConfig;
 use crate::errors::{OrderErrorKind, PolyError, Result};
-use crate::types::{ExtraOrderArgs, MarketOrderArgs, OrderOptions, Side, SignedOrderRequest};
-use alloy_primitives::{Address, U256};
-use alloy_signer_local::PrivateKeySigner;
+use crate::types::{
+    ExtraOrderArgs, MarketOrderArgs, OrderOptions, OrderType, Side, SignedOrderRequest,
+};
+use alloy_primitives::{Address, B256, Signature, U256};
+use alloy_sol_types::SolStruct;
 use rand::Rng;
 use rust_decimal::Decimal;
 use rust_decimal::RoundingStrategy::{AwayFromZero, MidpointTowardZero, ToZero};
 use rust_decimal::prelude::ToPrimitive;
 use std::collections::HashMap;
 use std::str::FromStr;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Signature types for orders
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum SigType {
     /// ECDSA EIP712 signatures signed by EOAs
     Eoa = 0,
@@ -29,6 +32,68 @@ pub enum SigType {
     PolyGnosisSafe = 2,
 }
 
+impl SigType {
+    /// Whether orders are funded by a wallet contract rather than the signer
+    pub fn uses_funder_wallet(&self) -> bool {
+        !matches!(self, SigType::Eoa)
+    }
+}
+
+impl TryFrom<u8> for SigType {
+    type Error = PolyError;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(SigType::Eoa),
+            1 => Ok(SigType::PolyProxy),
+            2 => Ok(SigType::PolyGnosisSafe),
+            other => Err(PolyError::validation(format!(
+                "Invalid signature type: {}",
+                other
+            ))),
+        }
+    }
+}
+
+impl FromStr for SigType {
+    type Err = PolyError;
+
+    /// Accepts the numeric value or `eoa`, `proxy` / `poly_proxy` and
+    /// `safe` / `gnosis_safe` / `poly_gnosis_safe`.
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "0" | "eoa" => Ok(SigType::Eoa),
+            "1" | "proxy" | "poly_proxy" => Ok(SigType::PolyProxy),
+            "2" | "safe" | "gnosis_safe" | "poly_gnosis_safe" => Ok(SigType::PolyGnosisSafe),
+            other => Err(PolyError::validation(format!(
+                "Invalid signature type: {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// Check that a signature type and funder address belong together.
+///
+/// EOA orders are funded by the signer itself. Proxy and Safe orders are
+/// funded by a separate wallet contract owned by the signer.
+pub fn validate_funder(signer: Address, sig_type: SigType, funder: Address) -> Result<()> {
+    if sig_type.uses_funder_wallet() {
+        if funder.is_zero() || funder == signer {
+            return Err(PolyError::validation(format!(
+                "{:?} orders need the funding wallet address as funder, not {}",
+                sig_type, funder
+            )));
+        }
+    } else if funder != signer {
+        return Err(PolyError::validation(format!(
+            "EOA orders must be funded by the signer {}, got funder {}",
+            signer, funder
+        )));
+    }
+    Ok(())
+}
+
 /// Rounding configuration for different tick sizes
 pub struct RoundConfig {
     price: u32,
@@ -36,16 +101,9 @@ pub struct RoundConfig {
     amount: u32,
 }
 
-/// Contract configuration
-pub struct ContractConfig {
-    pub exchange: String,
-    pub collateral: String,
-    pub conditional_tokens: String,
-}
-
 /// Order builder for creating and signing orders
 pub struct OrderBuilder {
-    signer: PrivateKeySigner,
+    signer: Arc<dyn PolySigner>,
     sig_type: SigType,
     funder: Address,
 }
@@ -88,21 +146,66 @@ static ROUNDING_CONFIG: LazyLock<HashMap<Decimal, RoundConfig>> = LazyLock::new(
     ])
 });
 
-/// Get contract configuration for chain
-pub fn get_contract_config(chain_id: u64, neg_risk: bool) -> Option<ContractConfig> {
-    match (chain_id, neg_risk) {
-        (137, false) => Some(ContractConfig {
-            exchange: "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E".to_string(),
-            collateral: "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174".to_string(),
-            conditional_tokens: "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045".to_string(),
-        }),
-        (137, true) => Some(ContractConfig {
-            exchange: "0xC5d563A36AE78145C45a50134d48A1215220f80a".to_string(),
-            collateral: "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174".to_string(),
-            conditional_tokens: "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045".to_string(),
-        }),
-        _ => None,
+/// Address of the exchange contract that verifies orders for `chain_id`
+pub fn exchange_address(chain_id: u64, neg_risk: bool) -> Result<Address> {
+    let contract_config = crate::config::get_contract_config(chain_id, neg_risk)
+        .ok_or_else(|| PolyError::config("No contract found with given chain_id and neg_risk"))?;
+
+    Address::from_str(&contract_config.exchange)
+        .map_err(|e| PolyError::config(format!("Invalid exchange address: {}", e)))
+}
+
+/// Exchange address from a network's contract config
+pub fn network_exchange_address(network: &NetworkConfig, neg_risk: bool) -> Result<Address> {
+    let risk_type = if neg_risk { "neg_risk" } else { "standard" };
+    let contract_config = network.get_contract(risk_type).ok_or_else(|| {
+        PolyError::config(format!(
+            "No {} exchange configured for {}",
+            risk_type, network.name
+        ))
+    })?;
+
+    Address::from_str(&contract_config.exchange)
+        .map_err(|e| PolyError::config(format!("Invalid exchange address: {}", e)))
+}
+
+fn offline_extras(extras: Option<&ExtraOrderArgs>, options: &OrderOptions) -> ExtraOrderArgs {
+    extras.cloned().unwrap_or_else(|| ExtraOrderArgs {
+        fee_rate_bps: options.fee_rate_bps.unwrap_or_default(),
+        ..Default::default()
+    })
+}
+
+/// Tick size and neg-risk flag of fully specified options
+fn offline_options(options: &OrderOptions) -> Result<(Decimal, bool)> {
+    let tick_size = options
+        .tick_size
+        .ok_or_else(|| PolyError::validation("Offline signing requires a tick size"))?;
+    if !ROUNDING_CONFIG.contains_key(&tick_size) {
+        return Err(PolyError::validation(format!(
+            "Unsupported tick size: {}",
+            tick_size
+        )));
     }
+    let neg_risk = options
+        .neg_risk
+        .ok_or_else(|| PolyError::validation("Offline signing requires the neg_risk flag"))?;
+    Ok((tick_size, neg_risk))
+}
+
+/// Without a server to reject it, an off-grid price would otherwise be
+/// silently rounded to a different one
+fn check_price_on_grid(price: Decimal, tick_size: Decimal) -> Result<()> {
+    if price < tick_size || price > Decimal::ONE - tick_size {
+        return Err(PolyError::validation("Price is not in range of tick_size"));
+    }
+    if !(price % tick_size).is_zero() {
+        return Err(PolyError::validation(format!(
+            "Price {} is not a multiple of tick size {}",
+            price, tick_size
+        )));
+    }
+    Ok(())
 }
 
 /// Generate a random seed for order salt
@@ -141,20 +244,24 @@ fn decimal_to_token_units(amount: Decimal) -> Result<u64> {
 }
 
 impl OrderBuilder {
-    /// Create a new order builder
+    /// Create a new order builder.
+    ///
+    /// `sig_type` defaults to EOA and `funder` to the signer; the pair must
+    /// pass [`validate_funder`].
     pub fn new(
-        signer: PrivateKeySigner,
+        signer: Arc<dyn PolySigner>,
         sig_type: Option<SigType>,
         funder: Option<Address>,
-    ) -> Self {
+    ) -> Result<Self> {
         let sig_type = sig_type.unwrap_or(SigType::Eoa);
         let funder = funder.unwrap_or(signer.address());
+        validate_funder(signer.address(), sig_type, funder)?;
 
-        OrderBuilder {
+        Ok(OrderBuilder {
             signer,
             sig_type,
             funder,
-        }
+        })
     }
 
     /// Get signature type as u8
@@ -162,10 +269,24 @@ impl OrderBuilder {
         self.sig_type as u8
     }
 
-    /// Override the funder/maker address for signed orders.
-    pub fn set_funder(&mut self, funder: Address) {
-        self.sig_type = SigType::PolyProxy;
+    pub fn sig_type(&self) -> SigType {
+        self.sig_type
+    }
+
+    /// Address that funds (is the maker of) signed orders
+    pub fn funder(&self) -> Address {
+        self.funder
+    }
+
+    /// Override the funder/maker address and the matching signature type.
+    ///
+    /// Fails, leaving the builder untouched, when the pair is inconsistent
+    /// (see [`validate_funder`]).
+    pub fn set_funder(&mut self, funder: Address, sig_type: SigType) -> Result<()> {
+        validate_funder(self.signer.address(), sig_type, funder)?;
+        self.sig_type = sig_type;
         self.funder = funder;
+        Ok(())
     }
 
     /// Fix amount rounding according to configuration
@@ -213,8 +334,12 @@ impl OrderBuilder {
     }
 
     /// Get order amounts for a market order
+    ///
+    /// For buys `amount` is the USDC to spend, for sells it is the number of
+    /// shares to sell.
     fn get_market_order_amounts(
         &self,
+        side: Side,
         amount: Decimal,
         price: Decimal,
         round_config: &RoundConfig,
@@ -222,7 +347,10 @@ impl OrderBuilder {
         let raw_maker_amt = amount.round_dp_with_strategy(round_config.size, ToZero);
         let raw_price = price.round_dp_with_strategy(round_config.price, MidpointTowardZero);
 
-        let raw_taker_amt = raw_maker_amt / raw_price;
+        let raw_taker_amt = match side {
+            Side::BUY => raw_maker_amt / raw_price,
+            Side::SELL => raw_maker_amt * raw_price,
+        };
         let raw_taker_amt = self.fix_amount_rounding(raw_taker_amt, round_config);
 
         Ok((
@@ -232,31 +360,44 @@ impl OrderBuilder {
     }
 
     /// Calculate market price from order book levels
+    ///
+    /// `positions` must be ordered best price first: asks for buys, bids for
+    /// sells. Buys accumulate notional (size * price) against a USDC amount,
+    /// sells accumulate shares. If the book is too thin, `FOK` orders fail
+    /// while `FAK` orders are priced at the deepest level.
     pub fn calculate_market_price(
         &self,
         positions: &[crate::types::BookLevel],
         amount_to_match: Decimal,
+        side: Side,
+        order_type: OrderType,
     ) -> Result<Decimal> {
         let mut sum = Decimal::ZERO;
 
         for level in positions {
-            sum += level.size * level.price;
+            sum += match side {
+                Side::BUY => level.size * level.price,
+                Side::SELL => level.size,
+            };
             if sum >= amount_to_match {
                 return Ok(level.price);
             }
         }
 
-        Err(PolyError::order(
-            format!(
-                "Not enough liquidity to create market order with amount {}",
-                amount_to_match
-            ),
-            crate::errors::OrderErrorKind::InsufficientBalance,
-        ))
+        match (order_type, positions.last()) {
+            (OrderType::FAK, Some(level)) => Ok(level.price),
+            _ => Err(PolyError::order(
+                format!(
+                    "Not enough liquidity to create market order with amount {}",
+                    amount_to_match
+                ),
+                crate::errors::OrderErrorKind::InsufficientBalance,
+            )),
+        }
     }
 
     /// Create a market order
-    pub fn create_market_order(
+    pub async fn create_market_order(
         &self,
         chain_id: u64,
         order_args: &MarketOrderArgs,
@@ -268,23 +409,22 @@ impl OrderBuilder {
             .tick_size
             .ok_or_else(|| PolyError::validation("Cannot create order without tick size"))?;
 
-        let (maker_amount, taker_amount) =
-            self.get_market_order_amounts(order_args.amount, price, &ROUNDING_CONFIG[&tick_size])?;
+        let (maker_amount, taker_amount) = self.get_market_order_amounts(
+            order_args.side,
+            order_args.amount,
+            price,
+            &ROUNDING_CONFIG[&tick_size],
+        )?;
 
         let neg_risk = options
             .neg_risk
             .ok_or_else(|| PolyError::validation("Cannot create order without neg_risk"))?;
 
-        let contract_config = get_contract_config(chain_id, neg_risk).ok_or_else(|| {
-            PolyError::config("No contract found with given chain_id and neg_risk")
-        })?;
-
-        let exchange_address = Address::from_str(&contract_config.exchange)
-            .map_err(|e| PolyError::config(format!("Invalid exchange address: {}", e)))?;
+        let exchange_address = exchange_address(chain_id, neg_risk)?;
 
         self.build_signed_order(
             order_args.token_id.clone(),
-            Side::BUY,
+            order_args.side,
             chain_id,
             exchange_address,
             maker_amount,
@@ -292,10 +432,11 @@ impl OrderBuilder {
             0,
             extras,
         )
+        .await
     }
 
     /// Create a regular order
-    pub fn create_order(
+    pub async fn create_order(
         &self,
         chain_id: u64,
         order_args: &OrderArgs,
@@ -318,12 +459,7 @@ impl OrderBuilder {
             .neg_risk
             .ok_or_else(|| PolyError::validation("Cannot create order without neg_risk"))?;
 
-        let contract_config = get_contract_config(chain_id, neg_risk).ok_or_else(|| {
-            PolyError::config("No contract found with given chain_id and neg_risk")
-        })?;
-
-        let exchange_address = Address::from_str(&contract_config.exchange)
-            .map_err(|e| PolyError::config(format!("Invalid exchange address: {}", e)))?;
+        let exchange_address = exchange_address(chain_id, neg_risk)?;
 
         self.build_signed_order(
             order_args.token_id.clone(),
@@ -335,11 +471,83 @@ impl OrderBuilder {
             expiration,
             extras,
         )
+        .await
+    }
+
+    /// Sign a limit order without any network access.
+    ///
+    /// `options` must carry the tick size and neg-risk flag, and the exchange
+    /// comes from `network`. When `extras` is `None` the fee rate is taken
+    /// from `options.fee_rate_bps`.
+    pub async fn sign_order_offline(
+        &self,
+        network: &NetworkConfig,
+        order_args: &OrderArgs,
+        expiration: u64,
+        extras: Option<&ExtraOrderArgs>,
+        options: &OrderOptions,
+    ) -> Result<SignedOrderRequest> {
+        let (tick_size, neg_risk) = offline_options(options)?;
+        check_price_on_grid(order_args.price, tick_size)?;
+        let extras = offline_extras(extras, options);
+
+        let (maker_amount, taker_amount) = self.get_order_amounts(
+            order_args.side,
+            order_args.size,
+            order_args.price,
+            &ROUNDING_CONFIG[&tick_size],
+        )?;
+
+        self.build_signed_order(
+            order_args.token_id.clone(),
+            order_args.side,
+            network.chain_id,
+            network_exchange_address(network, neg_risk)?,
+            maker_amount,
+            taker_amount,
+            expiration,
+            &extras,
+        )
+        .await
+    }
+
+    /// Sign a market order at a given worst `price` without any network
+    /// access. See [`Self::sign_order_offline`].
+    pub async fn sign_market_order_offline(
+        &self,
+        network: &NetworkConfig,
+        order_args: &MarketOrderArgs,
+        price: Decimal,
+        extras: Option<&ExtraOrderArgs>,
+        options: &OrderOptions,
+    ) -> Result<SignedOrderRequest> {
+        let (tick_size, neg_risk) = offline_options(options)?;
+        check_price_on_grid(price, tick_size)?;
+        let extras = offline_extras(extras, options);
+
+        let (maker_amount, taker_amount) = self.get_market_order_amounts(
+            order_args.side,
+            order_args.amount,
+            price,
+            &ROUNDING_CONFIG[&tick_size],
+        )?;
+
+        self.build_signed_order(
+            order_args.token_id.clone(),
+            order_args.side,
+            network.chain_id,
+            network_exchange_address(network, neg_risk)?,
+            maker_amount,
+            taker_amount,
+            0,
+            &extras,
+        )
+        .await
     }
 
     /// Build and sign an order
     #[allow(clippy::too_many_arguments)]
-    fn build_signed_order(
+    async fn build_signed_order(
         &self,
         token_id: String,
         side: Side,
@@ -357,7 +565,7 @@ impl OrderBuilder {
         let u256_token_id = U256::from_str_radix(&token_id, 10)
             .map_err(|e| PolyError::validation(format!("Incorrect tokenId format: {}", e)))?;
 
-        let order = crate::auth::Order {
+        let order = Order {
             salt: U256::from(seed),
             maker: self.funder,
             signer: self.signer.address(),
@@ -372,7 +580,7 @@ impl OrderBuilder {
             signatureType: self.sig_type as u8,
         };
 
-        let signature = sign_order_message(&self.signer, order, chain_id, exchange)?;
+        let signature = sign_order_message(&self.signer, order, chain_id, exchange).await?;
 
         Ok(SignedOrderRequest {
             salt: seed,
@@ -392,9 +600,136 @@ impl OrderBuilder {
     }
 }
 
+impl SignedOrderRequest {
+    /// The EIP-712 `Order` struct covered by the signature
+    pub fn to_eip712(&self) -> Result<Order> {
+        let address = |field: &str, value: &str| {
+            Address::from_str(value)
+                .map_err(|_| PolyError::validation(format!("Invalid {} address: {}", field, value)))
+        };
+        let uint = |field: &str, value: &str| {
+            U256::from_str_radix(value, 10)
+                .map_err(|_| PolyError::validation(format!("Invalid {}: {:?}", field, value)))
+        };
+        let side = match self.side.as_str() {
+            "BUY" => Side::BUY,
+            "SELL" => Side::SELL,
+            other => return Err(PolyError::validation(format!("Invalid side: {}", other))),
+        };
+
+        Ok(Order {
+            salt: U256::from(self.salt),
+            maker: address("maker", &self.maker)?,
+            signer: address("signer", &self.signer)?,
+            taker: address("taker", &self.taker)?,
+            tokenId: uint("token_id", &self.token_id)?,
+            makerAmount: uint("maker_amount", &self.maker_amount)?,
+            takerAmount: uint("taker_amount", &self.taker_amount)?,
+            expiration: uint("expiration", &self.expiration)?,
+            nonce: uint("nonce", &self.nonce)?,
+            feeRateBps: uint("fee_rate_bps", &self.fee_rate_bps)?,
+            side: side as u8,
+            signatureType: SigType::try_from(self.signature_type)? as u8,
+        })
+    }
+
+    /// Check that the order is well formed before it is submitted.
+    ///
+    /// `now_secs` is used to reject GTD orders that have already expired.
+    pub fn validate(&self, order_type: OrderType, now_secs: u64) -> Result<()> {
+        let order = self.to_eip712()?;
+        if order.tokenId.is_zero() {
+            return Err(PolyError::validation("Order is missing a token id"));
+        }
+        if order.makerAmount.is_zero() || order.takerAmount.is_zero() {
+            return Err(PolyError::order(
+                "Order amounts must be non-zero",
+                OrderErrorKind::InvalidSize,
+            ));
+        }
+        validate_funder(
+            order.signer,
+            SigType::try_from(order.signatureType)?,
+            order.maker,
+        )?;
+        let signature = self.signature.strip_prefix("0x").unwrap_or(&self.signature);
+        if signature.len() != 130 || !signature.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(PolyError::validation(
+                "Order signature is not a 65-byte hex string",
+            ));
+        }
+
+        if order_type == OrderType::GTD && order.expiration <= U256::from(now_secs) {
+            return Err(PolyError::validation(format!(
+                "GTD order expiration {} is not in the future",
+                self.expiration
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Exchange order hash, which the CLOB also uses as the order id
+    pub fn order_hash(&self, chain_id: u64, neg_risk: bool) -> Result<B256> {
+        self.order_hash_for(chain_id, exchange_address(chain_id, neg_risk)?)
+    }
+
+    /// Order hash for an explicit exchange contract
+    pub fn order_hash_for(&self, chain_id: u64, exchange: Address) -> Result<B256> {
+        let domain = order_domain(chain_id, exchange);
+        Ok(self.to_eip712()?.eip712_signing_hash(&domain))
+    }
+
+    /// Recover the address that produced `signature`
+    pub fn recover_signer(&self, chain_id: u64, neg_risk: bool) -> Result<Address> {
+        self.recover_from_hash(&self.order_hash(chain_id, neg_risk)?)
+    }
+
+    fn recover_from_hash(&self, hash: &B256) -> Result<Address> {
+        let bytes = alloy_primitives::hex::decode(&self.signature)
+            .map_err(|e| PolyError::validation(format!("Invalid order signature: {}", e)))?;
+        let signature = Signature::from_raw(&bytes)
+            .map_err(|e| PolyError::validation(format!("Invalid order signature: {}", e)))?;
+
+        signature
+            .recover_address_from_prehash(hash)
+            .map_err(|e| PolyError::crypto(format!("Failed to recover order signer: {}", e)))
+    }
+
+    /// Check the order offline and return its hash.
+    ///
+    /// Verifies that the signature type, maker and signer belong together and
+    /// that the signature was produced by `signer` for this exact order.
+    pub fn verify(&self, chain_id: u64, neg_risk: bool) -> Result<B256> {
+        self.verify_for(chain_id, exchange_address(chain_id, neg_risk)?)
+    }
+
+    /// [`Self::verify`] against an explicit exchange contract, e.g. one from
+    /// a custom [`NetworkConfig`]
+    pub fn verify_for(&self, chain_id: u64, exchange: Address) -> Result<B256> {
+        let order = self.to_eip712()?;
+        validate_funder(
+            order.signer,
+            SigType::try_from(order.signatureType)?,
+            order.maker,
+        )?;
+
+        let hash = order.eip712_signing_hash(&order_domain(chain_id, exchange));
+        let recovered = self.recover_from_hash(&hash)?;
+        if recovered != order.signer {
+            return Err(PolyError::crypto(format!(
+                "Order is signed by {}, not by its signer {}",
+                recovered, order.signer
+            )));
+        }
+        Ok(hash)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy_signer_local::PrivateKeySigner;
 
     #[test]
     fn test_decimal_to_token_units() {
@@ -424,6 +759,181 @@ mod tests {
         assert_eq!(result, 1_000_000_000);
     }
 
+    #[test]
+    fn test_validate_funder() {
+        let signer = Address::repeat_byte(1);
+        let wallet = Address::repeat_byte(2);
+
+        assert!(validate_funder(signer, SigType::Eoa, signer).is_ok());
+        assert!(validate_funder(signer, SigType::Eoa, wallet).is_err());
+        assert!(validate_funder(signer, SigType::PolyProxy, wallet).is_ok());
+        assert!(validate_funder(signer, SigType::PolyGnosisSafe, wallet).is_ok());
+        assert!(validate_funder(signer, SigType::PolyGnosisSafe, signer).is_err());
+        assert!(validate_funder(signer, SigType::PolyProxy, Address::ZERO).is_err());
+
+        assert_eq!("safe".parse::<SigType>().unwrap(), SigType::PolyGnosisSafe);
+        assert_eq!(SigType::try_from(1).unwrap(), SigType::PolyProxy);
+        assert!(SigType::try_from(3).is_err());
+    }
+
+    #[test]
+    fn test_set_funder_keeps_sig_type() {
+        let signer: PrivateKeySigner =
+            "0x1234567890123456789012345678901234567890123456789012345678901234"
+                .parse()
+                .unwrap();
+        let signer_address = signer.address();
+        let signer: Arc<dyn PolySigner> = Arc::new(signer);
+        let safe = Address::repeat_byte(2);
+        assert!(OrderBuilder::new(signer.clone(), Some(SigType::PolyProxy), None).is_err());
+        assert!(OrderBuilder::new(signer.clone(), None, Some(safe)).is_err());
+        let mut builder = OrderBuilder::new(signer, None, None).unwrap();
+
+        builder.set_funder(safe, SigType::PolyGnosisSafe).unwrap();
+        assert_eq!(builder.sig_type(), SigType::PolyGnosisSafe);
+        assert_eq!(builder.funder(), safe);
+
+        // A rejected pair leaves the previous configuration in place
+        assert!(builder.set_funder(safe, SigType::Eoa).is_err());
+        assert_eq!(builder.sig_type(), SigType::PolyGnosisSafe);
+
+        builder.set_funder(signer_address, SigType::Eoa).unwrap();
+        assert_eq!(builder.get_sig_type(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_verify_signed_order() {
+        let signer: PrivateKeySigner =
+            "0x1234567890123456789012345678901234567890123456789012345678901234"
+                .parse()
+                .unwrap();
+        let builder = OrderBuilder::new(Arc::new(signer), None, None).unwrap();
+        let options = OrderOptions {
+            tick_size: Some(Decimal::from_str("0.01").unwrap()),
+            neg_risk: Some(false),
+            fee_rate_bps: None,
+        };
+        let args = OrderArgs::new(
+            "1234",
+            Decimal::from_str("0.5").unwrap(),
+            Decimal::from(10),
+            Side::BUY,
+        );
+        let order = builder
+            .create_order(137, &args, 0, &ExtraOrderArgs::default(), &options)
+            .await
+            .unwrap();
+
+        let hash = order.verify(137, false).unwrap();
+        assert_eq!(hash, order.order_hash(137, false).unwrap());
+        assert_eq!(
+            order.recover_signer(137, false).unwrap(),
+            Address::from_str(&order.signer).unwrap()
+        );
+
+        // The neg-risk exchange is a different domain
+        assert!(order.verify(137, true).is_err());
+
+        let mut tampered = order.clone();
+        tampered.maker_amount = "1".to_string();
+        assert!(matches!(
+            tampered.verify(137, false),
+            Err(PolyError::Auth { .. })
+        ));
+
+        let mut wrong_type = order.clone();
+        wrong_type.signature_type = SigType::PolyProxy as u8;
+        assert!(matches!(
+            wrong_type.verify(137, false),
+            Err(PolyError::Validation { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sign_order_offline() {
+        let signer: PrivateKeySigner =
+            "0x1234567890123456789012345678901234567890123456789012345678901234"
+                .parse()
+                .unwrap();
+        let builder = OrderBuilder::new(Arc::new(signer), None, None).unwrap();
+        let network = NetworkConfig::polygon_mumbai();
+        let args = OrderArgs::new(
+            "1234",
+            Decimal::from_str("0.25").unwrap(),
+            Decimal::from(8),
+            Side::BUY,
+        );
+        let options = OrderOptions {
+            tick_size: Some(Decimal::from_str("0.01").unwrap()),
+            neg_risk: Some(true),
+            fee_rate_bps: Some(20),
+        };
+
+        let order = builder
+            .sign_order_offline(&network, &args, 0, None, &options)
+            .await
+            .unwrap();
+        assert_eq!(order.fee_rate_bps, "20");
+        assert_eq!(order.maker_amount, "2000000");
+        let offline =
+            crate::types::OfflineOrder::new(&network, order, OrderType::GTC, true).unwrap();
+        let parsed = crate::types::OfflineOrder::from_json(&offline.to_json().unwrap()).unwrap();
+        assert_eq!(
+            parsed.exchange,
+            network_exchange_address(&network, true).unwrap()
+        );
+        assert!(parsed.order.verify_for(80002, parsed.exchange).is_ok());
+
+        // A custom network's exchange is a different domain
+        let mut custom = network.clone();
+        custom.contracts.get_mut("neg_risk").unwrap().exchange =
+            "0x0000000000000000000000000000000000000042".to_string();
+        let custom_order = builder
+            .sign_order_offline(&custom, &args, 0, None, &options)
+            .await
+            .unwrap();
+        let custom_offline =
+            crate::types::OfflineOrder::new(&custom, custom_order, OrderType::GTC, true).unwrap();
+        assert!(
+            custom_offline
+                .order
+                .verify_for(80002, custom_offline.exchange)
+                .is_ok()
+        );
+        assert!(custom_offline.order.verify(80002, true).is_err());
+
+        let incomplete = OrderOptions {
+            neg_risk: None,
+            ..options.clone()
+        };
+        assert!(
+            builder
+                .sign_order_offline(&network, &args, 0, None, &incomplete)
+                .await
+                .is_err()
+        );
+        let out_of_range = OrderArgs {
+            price: Decimal::from_str("0.995").unwrap(),
+            ..args.clone()
+        };
+        assert!(
+            builder
+                .sign_order_offline(&network, &out_of_range, 0, None, &options)
+                .await
+                .is_err()
+        );
+        let off_grid = OrderArgs {
+            price: Decimal::from_str("0.255").unwrap(),
+            ..args
+        };
+        assert!(matches!(
+            builder
+                .sign_order_offline(&network, &off_grid, 0, None, &options)
+                .await,
+            Err(PolyError::Validation { .. })
+        ));
+    }
+
     #[test]
     fn test_decimal_to_token_units_overflow() {
         let amount = Decimal::from(u64::MAX);
@@ -451,18 +961,18 @@ mod tests {
     }
 
     #[test]
-    fn test_get_contract_config() {
-        // Test Polygon mainnet
-        let config = get_contract_config(137, false);
-        assert!(config.is_some());
+    fn test_exchange_address() {
+        // Polygon mainnet
+        assert!(exchange_address(137, false).is_ok());
 
-        // Test with neg risk
-        let config_neg = get_contract_config(137, true);
-        assert!(config_neg.is_some());
+        // Neg risk exchange
+        assert_ne!(
+            exchange_address(137, true).unwrap(),
+            exchange_address(137, false).unwrap()
+        );
 
-        // Test unsupported chain
-        let config_unsupported = get_contract_config(999, false);
-        assert!(config_unsupported.is_none());
+        // Unsupported chain
+        assert!(exchange_address(999, false).is_err());
     }
 
     #[test]
@@ -485,4 +995,78 @@ mod tests {
             assert!(seed < u64::MAX);
         }
     }
+
+    fn test_builder() -> OrderBuilder {
+        let signer = PrivateKeySigner::from_str(
+            "0x1234567890123456789012345678901234567890123456789012345678901234",
+        )
+        .unwrap();
+        OrderBuilder::new(Arc::new(signer), None, None).unwrap()
+    }
+
+    fn level(price: &str, size: &str) -> crate::types::BookLevel {
+        crate::types::BookLevel {
+            price: Decimal::from_str(price).unwrap(),
+            size: Decimal::from_str(size).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_calculate_market_price_sell_walks_shares() {
+        let builder = test_builder();
+        let bids = vec![
+            level("0.60", "10"),
+            level("0.55", "20"),
+            level("0.50", "50"),
+        ];
+
+        // 25 shares exhaust the first bid and part of the second
+        let price = builder
+            .calculate_market_price(&bids, Decimal::from(25), Side::SELL, OrderType::FOK)
+            .unwrap();
+        assert_eq!(price, Decimal::from_str("0.55").unwrap());
+    }
+
+    #[test]
+    fn test_calculate_market_price_fok_vs_fak() {
+        let builder = test_builder();
+        let asks = vec![level("0.40", "10"), level("0.45", "10")];
+
+        // Only 8.5 USDC of liquidity on the book
+        let amount = Decimal::from(20);
+        let err = builder
+            .calculate_market_price(&asks, amount, Side::BUY, OrderType::FOK)
+            .unwrap_err();
+        assert!(matches!(err, PolyError::Order { .. }));
+
+        let price = builder
+            .calculate_market_price(&asks, amount, Side::BUY, OrderType::FAK)
+            .unwrap();
+        assert_eq!(price, Decimal::from_str("0.45").unwrap());
+
+        assert!(
+            builder
+                .calculate_market_price(&[], amount, Side::BUY, OrderType::FAK)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_get_market_order_amounts_by_side() {
+        let builder = test_builder();
+        let round_config = &ROUNDING_CONFIG[&Decimal::from_str("0.01").unwrap()];
+        let price = Decimal::from_str("0.5").unwrap();
+
+        // Buy: spend 10 USDC for 20 shares
+        let (maker, taker) = builder
+            .get_market_order_amounts(Side::BUY, Decimal::from(10), price, round_config)
+            .unwrap();
+        assert_eq!((maker, taker), (10_000_000, 20_000_000));
+
+        // Sell: give 10 shares for 5 USDC
+        let (maker, taker) = builder
+            .get_market_order_amounts(Side::SELL, Decimal::from(10), price, round_config)
+            .unwrap();
+        assert_eq!((maker, taker), (10_000_000, 5_000_000));
+    }
 }
@@ -0,0 +1,245 @@
+//! Liquidity reward eligibility and score estimates
+//!
+//! Follows the published scoring function: an order within `max_spread`
+//! cents of the midpoint and at least `min_size` shares earns
+//! `((v - s) / v)^2 * b * size`, where `v` is the max spread, `s` the order's
+//! distance from the midpoint and `b` the in-game multiplier. The exchange
+//! uses a size-cutoff-adjusted midpoint; the plain book midpoint is used here,
+//! so scores are estimates.
+
+use crate::book::OrderBook;
+use crate::types::{OpenOrder, Rewards, Side};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+/// Penalty factor applied to single-sided liquidity
+pub const SINGLE_SIDED_PENALTY: Decimal = dec!(3);
+
+/// Why a quote does or does not earn rewards
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Eligibility {
+    Eligible,
+    /// Remaining size is below the market's `min_size`
+    BelowMinSize,
+    /// Further from the midpoint than the market's `max_spread`
+    OutsideMaxSpread,
+    /// The order is for a different token than the book
+    OtherToken,
+    /// The book has no two-sided midpoint to measure against
+    NoMidpoint,
+}
+
+/// Score estimate for one open order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuoteScore {
+    pub order_id: String,
+    pub side: Side,
+    pub price: Decimal,
+    /// Unfilled size in shares
+    pub size: Decimal,
+    /// Distance from the midpoint in cents, when a midpoint exists
+    pub distance: Option<Decimal>,
+    pub eligibility: Eligibility,
+    pub score: Decimal,
+}
+
+impl QuoteScore {
+    pub fn is_eligible(&self) -> bool {
+        self.eligibility == Eligibility::Eligible
+    }
+}
+
+/// Reward score estimate for a set of quotes on one book
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RewardEstimate {
+    pub midpoint: Option<Decimal>,
+    pub quotes: Vec<QuoteScore>,
+    /// Combined score of eligible bids
+    pub bid_score: Decimal,
+    /// Combined score of eligible asks
+    pub ask_score: Decimal,
+    /// Market score after the two-sided requirement is applied
+    pub score: Decimal,
+}
+
+/// Estimate which of `orders` qualify for liquidity rewards against `book`
+/// and what they would score.
+///
+/// Inside the `[0.10, 0.90]` midpoint band single-sided quotes still score,
+/// at a third of their value; outside it only two-sided liquidity counts.
+pub fn estimate_rewards(
+    orders: &[OpenOrder],
+    book: &OrderBook,
+    rewards: &Rewards,
+) -> RewardEstimate {
+    let midpoint = book.mid_price();
+    let max_spread = rewards.max_spread;
+    let multiplier = rewards.in_game_multiplier.unwrap_or(Decimal::ONE);
+
+    let quotes: Vec<QuoteScore> = orders
+        .iter()
+        .map(|order| {
+            let size = order.original_size - order.size_matched;
+            let distance = midpoint.map(|mid| (order.price - mid).abs() * dec!(100));
+            let eligibility = if order.asset_id != book.token_id {
+                Eligibility::OtherToken
+            } else if size < rewards.min_size {
+                Eligibility::BelowMinSize
+            } else {
+                match distance {
+                    None => Eligibility::NoMidpoint,
+                    Some(d) if d > max_spread || max_spread.is_zero() => {
+                        Eligibility::OutsideMaxSpread
+                    }
+                    Some(_) => Eligibility::Eligible,
+                }
+            };
+            let score = match (eligibility, distance) {
+                (Eligibility::Eligible, Some(d)) => {
+                    let closeness = (max_spread - d) / max_spread;
+                    closeness * closeness * multiplier * size
+                }
+                _ => Decimal::ZERO,
+            };
+
+            QuoteScore {
+                order_id: order.id.clone(),
+                side: order.side,
+                price: order.price,
+                size,
+                distance,
+                eligibility,
+                score,
+            }
+        })
+        .collect();
+
+    let side_score = |side: Side| -> Decimal {
+        quotes
+            .iter()
+            .filter(|q| q.side == side)
+            .map(|q| q.score)
+            .sum()
+    };
+    let bid_score = side_score(Side::BUY);
+    let ask_score = side_score(Side::SELL);
+
+    let two_sided = bid_score.min(ask_score);
+    let score = match midpoint {
+        Some(mid) if mid >= dec!(0.10) && mid <= dec!(0.90) => {
+            two_sided.max((bid_score / SINGLE_SIDED_PENALTY).max(ask_score / SINGLE_SIDED_PENALTY))
+        }
+        _ => two_sided,
+    };
+
+    RewardEstimate {
+        midpoint,
+        quotes,
+        bid_score,
+        ask_score,
+        score,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{OrderDelta, OrderType};
+    use chrono::Utc;
+
+    fn book(bid: Decimal, ask: Decimal) -> OrderBook {
+        let mut book = OrderBook::new("token".to_string(), 10);
+        for (sequence, side, price) in [(1, Side::BUY, bid), (2, Side::SELL, ask)] {
+            book.apply_delta(OrderDelta {
+                token_id: "token".to_string(),
+                timestamp: Utc::now(),
+                side,
+                price,
+                size: dec!(1000),
+                sequence,
+            })
+            .unwrap();
+        }
+        book
+    }
+
+    fn order(id: &str, side: Side, price: Decimal, size: Decimal) -> OpenOrder {
+        OpenOrder {
+            associate_trades: Vec::new(),
+            id: id.to_string(),
+            status: "LIVE".to_string(),
+            market: "0xabc".to_string(),
+            original_size: size,
+            outcome: "Yes".to_string(),
+            maker_address: String::new(),
+            owner: String::new(),
+            price,
+            side,
+            size_matched: Decimal::ZERO,
+            asset_id: "token".to_string(),
+            expiration: 0,
+            order_type: OrderType::GTC,
+            created_at: 0,
+        }
+    }
+
+    fn rewards() -> Rewards {
+        Rewards {
+            rates: None,
+            min_size: dec!(50),
+            max_spread: dec!(3),
+            event_start_date: None,
+            event_end_date: None,
+            in_game_multiplier: None,
+            reward_epoch: None,
+        }
+    }
+
+    #[test]
+    fn test_quote_eligibility() {
+        let book = book(dec!(0.49), dec!(0.51));
+        let orders = vec![
+            order("tight", Side::BUY, dec!(0.49), dec!(100)),
+            order("small", Side::BUY, dec!(0.49), dec!(10)),
+            order("wide", Side::SELL, dec!(0.55), dec!(100)),
+            order("ask", Side::SELL, dec!(0.52), dec!(100)),
+        ];
+        let estimate = estimate_rewards(&orders, &book, &rewards());
+
+        assert_eq!(estimate.midpoint, Some(dec!(0.50)));
+        let eligibility: Vec<_> = estimate.quotes.iter().map(|q| q.eligibility).collect();
+        assert_eq!(
+            eligibility,
+            vec![
+                Eligibility::Eligible,
+                Eligibility::BelowMinSize,
+                Eligibility::OutsideMaxSpread,
+                Eligibility::Eligible,
+            ]
+        );
+
+        // 1c from mid with a 3c max spread: ((3 - 1) / 3)^2 * 100
+        let tight = &estimate.quotes[0];
+        assert_eq!(tight.distance, Some(dec!(1)));
+        assert_eq!(tight.score.round_dp(6), dec!(44.444444));
+        assert_eq!(estimate.bid_score, tight.score);
+        // The thin ask side scores less than a third of the bids, so the
+        // single-sided bid score wins
+        assert_eq!(estimate.ask_score.round_dp(6), dec!(11.111111));
+        assert_eq!(estimate.score, estimate.bid_score / SINGLE_SIDED_PENALTY);
+    }
+
+    #[test]
+    fn test_single_sided_scoring() {
+        let orders = vec![order("bid", Side::BUY, dec!(0.49), dec!(90))];
+        let estimate = estimate_rewards(&orders, &book(dec!(0.49), dec!(0.51)), &rewards());
+        assert_eq!(estimate.ask_score, Decimal::ZERO);
+        assert_eq!(estimate.score, estimate.bid_score / SINGLE_SIDED_PENALTY);
+
+        // Outside the [0.10, 0.90] band one-sided liquidity earns nothing
+        let orders = vec![order("bid", Side::BUY, dec!(0.04), dec!(90))];
+        let estimate = estimate_rewards(&orders, &book(dec!(0.04), dec!(0.06)), &rewards());
+        assert!(estimate.bid_score > Decimal::ZERO);
+        assert_eq!(estimate.score, Decimal::ZERO);
+    }
+}
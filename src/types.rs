@@ -3,6 +3,7 @@
 //! This module defines all the stable public types used throughout the client.
 //! These types are optimized for latency-sensitive trading environments.
 
+use crate::auth::Redacted;
 use alloy_primitives::{Address, U256};
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
@@ -10,6 +11,7 @@ use rust_decimal::prelude::ToPrimitive;
 use serde::{Deserialize, Serialize};
 use serde_json;
 use std::fmt;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 // ============================================================================
 // FIXED-POINT OPTIMIZATION FOR HOT PATH PERFORMANCE
@@ -213,6 +215,8 @@ impl Side {
 pub enum OrderType {
     GTC,
     FOK,
+    /// Fill-and-kill: match as much as possible immediately, cancel the rest
+    FAK,
     GTD,
 }
 
@@ -221,6 +225,7 @@ impl OrderType {
         match self {
             OrderType::GTC => "GTC",
             OrderType::FOK => "FOK",
+            OrderType::FAK => "FAK",
             OrderType::GTD => "GTD",
         }
     }
@@ -484,8 +489,8 @@ pub struct Order {
     pub client_id: Option<String>,
 }
 
-/// API credentials for authentication
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+/// API credentials for authentication, wiped from memory on drop
+#[derive(Clone, Serialize, Deserialize, Default, Zeroize, ZeroizeOnDrop)]
 pub struct ApiCredentials {
     #[serde(rename = "apiKey")]
     pub api_key: String,
@@ -493,6 +498,16 @@ pub struct ApiCredentials {
     pub passphrase: String,
 }
 
+impl fmt::Debug for ApiCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApiCredentials")
+            .field("api_key", &self.api_key)
+            .field("secret", &Redacted(()))
+            .field("passphrase", &Redacted(()))
+            .finish()
+    }
+}
+
 /// Configuration for order creation
 #[derive(Debug, Clone)]
 pub struct OrderOptions {
@@ -520,10 +535,33 @@ impl Default for ExtraOrderArgs {
 }
 
 /// Market order arguments
+///
+/// `amount` is denominated in USDC for buys and in outcome shares for sells.
 #[derive(Debug, Clone)]
 pub struct MarketOrderArgs {
     pub token_id: String,
     pub amount: Decimal,
+    pub side: Side,
+    /// `FOK` rejects the order unless the book can fill it entirely, `FAK`
+    /// prices it at the deepest available level and lets it fill partially.
+    /// Any other order type is rejected.
+    pub order_type: OrderType,
+}
+
+impl MarketOrderArgs {
+    pub fn new(token_id: &str, amount: Decimal, side: Side) -> Self {
+        Self {
+            token_id: token_id.to_string(),
+            amount,
+            side,
+            order_type: OrderType::FOK,
+        }
+    }
+
+    pub fn with_order_type(mut self, order_type: OrderType) -> Self {
+        self.order_type = order_type;
+        self
+    }
 }
 
 /// Signed order request ready for submission
@@ -545,6 +583,47 @@ pub struct SignedOrderRequest {
     pub signature: String,
 }
 
+/// A signed order plus what is needed to submit it, for handing orders
+/// from an offline signer to an online process
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OfflineOrder {
+    pub order: SignedOrderRequest,
+    pub order_type: OrderType,
+    pub chain_id: u64,
+    /// Exchange contract the order was signed against
+    pub exchange: Address,
+}
+
+impl OfflineOrder {
+    /// Wrap an order signed for `network`'s standard or neg-risk exchange
+    pub fn new(
+        network: &crate::config::NetworkConfig,
+        order: SignedOrderRequest,
+        order_type: OrderType,
+        neg_risk: bool,
+    ) -> crate::errors::Result<Self> {
+        Ok(Self {
+            order,
+            order_type,
+            chain_id: network.chain_id,
+            exchange: crate::orders::network_exchange_address(network, neg_risk)?,
+        })
+    }
+
+    pub fn to_json(&self) -> crate::errors::Result<String> {
+        serde_json::to_string(self).map_err(|e| {
+            crate::errors::PolyError::parse(format!("Failed to serialize order: {}", e), None)
+        })
+    }
+
+    pub fn from_json(json: &str) -> crate::errors::Result<Self> {
+        serde_json::from_str(json).map_err(|e| {
+            crate::errors::PolyError::parse(format!("Failed to parse offline order: {}", e), None)
+        })
+    }
+}
+
 /// Post order wrapper
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -564,6 +643,182 @@ impl PostOrder {
     }
 }
 
+/// Outcome reported by the exchange for a single submitted order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderOutcome {
+    /// Order crossed the book and was matched on submission
+    Matched,
+    /// Order is resting on the book
+    Live,
+    /// Order is marketable but its match has been delayed by the exchange
+    Delayed,
+    /// Order is marketable but could not be matched
+    Unmatched,
+    /// Order was rejected, with the reason returned by the exchange
+    Rejected { reason: String },
+    /// Order was accepted with a status this client does not recognise
+    /// (empty when the exchange sent none)
+    Unknown(String),
+}
+
+impl OrderOutcome {
+    /// Whether the exchange accepted the order
+    pub fn is_accepted(&self) -> bool {
+        !matches!(self, OrderOutcome::Rejected { .. })
+    }
+
+    /// Typed reason for a rejection, when the exchange message is recognised
+    pub fn rejection_kind(&self) -> Option<crate::errors::OrderErrorKind> {
+        match self {
+            OrderOutcome::Rejected { reason } => {
+                crate::decode::classify_order_error(reason).map(|(_, kind)| kind)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Response for `POST /order` and for each entry of `POST /orders`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "crate::decode::RawPostOrderResponse")]
+pub struct PostOrderResponse {
+    /// Exchange order ID (may be empty when the order was rejected)
+    pub order_id: String,
+    pub outcome: OrderOutcome,
+    pub making_amount: Option<Decimal>,
+    pub taking_amount: Option<Decimal>,
+    pub transaction_hashes: Vec<String>,
+}
+
+impl PostOrderResponse {
+    /// Whether the exchange accepted the order
+    pub fn is_success(&self) -> bool {
+        self.outcome.is_accepted()
+    }
+
+    /// Convert a rejection into a typed [`PolyError::Order`](crate::errors::PolyError::Order)
+    pub fn into_result(self) -> crate::errors::Result<Self> {
+        match &self.outcome {
+            OrderOutcome::Rejected { reason } => Err(crate::decode::order_rejection_error(reason)),
+            _ => Ok(self),
+        }
+    }
+}
+
+/// Per-order results of a batch submission, in input order
+#[derive(Debug, Clone)]
+pub struct BatchPostResult {
+    pub results: Vec<crate::errors::Result<PostOrderResponse>>,
+}
+
+impl BatchPostResult {
+    /// Whether every order in the batch was accepted
+    pub fn all_succeeded(&self) -> bool {
+        self.results.iter().all(|result| result.is_ok())
+    }
+
+    /// Accepted orders with their index in the input batch
+    pub fn succeeded(&self) -> impl Iterator<Item = (usize, &PostOrderResponse)> {
+        self.results
+            .iter()
+            .enumerate()
+            .filter_map(|(i, result)| result.as_ref().ok().map(|r| (i, r)))
+    }
+
+    /// Failed orders with their index in the input batch
+    pub fn failed(&self) -> impl Iterator<Item = (usize, &crate::errors::PolyError)> {
+        self.results
+            .iter()
+            .enumerate()
+            .filter_map(|(i, result)| result.as_ref().err().map(|e| (i, e)))
+    }
+}
+
+/// Order of operations for [`ClobClient::replace_order`](crate::client::ClobClient::replace_order)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplaceMode {
+    /// Cancel the old order, then post the new one. Never leaves both on
+    /// the book, but the quote is briefly absent.
+    CancelThenPost,
+    /// Post the new order, then cancel the old one. The quote never leaves
+    /// the book, but both orders rest for a moment.
+    PostThenCancel,
+}
+
+/// Combined outcome of replacing an order
+#[derive(Debug, Clone)]
+pub enum ReplaceOutcome {
+    /// The old order was cancelled and the new one accepted
+    Replaced { new_order: PostOrderResponse },
+    /// The old order could not be cancelled. `new_order` is set when the
+    /// new order had already been posted (`PostThenCancel`), in which case
+    /// it is live; the old order's state is unknown, or it was reported in
+    /// the cancel response's `not_canceled`.
+    CancelFailed {
+        error: crate::errors::PolyError,
+        new_order: Option<PostOrderResponse>,
+    },
+    /// The new order was not accepted. `old_cancelled` tells whether the old
+    /// order had already been cancelled (`CancelThenPost`) or is still live.
+    PostFailed {
+        error: crate::errors::PolyError,
+        old_cancelled: bool,
+    },
+}
+
+impl ReplaceOutcome {
+    /// Whether the old order is gone and the new one is live
+    pub fn is_replaced(&self) -> bool {
+        matches!(self, ReplaceOutcome::Replaced { .. })
+    }
+}
+
+/// Response for the cancel endpoints (`/order`, `/orders`, `/cancel-all`,
+/// `/cancel-market-orders`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CancelOrdersResponse {
+    /// IDs of the orders that were cancelled
+    #[serde(default)]
+    pub canceled: Vec<String>,
+    /// Orders that could not be cancelled, keyed by ID, with the reason
+    #[serde(default)]
+    pub not_canceled: std::collections::HashMap<String, String>,
+}
+
+impl CancelOrdersResponse {
+    /// Check whether a given order ID was cancelled
+    pub fn is_canceled(&self, order_id: &str) -> bool {
+        self.canceled.iter().any(|id| id == order_id)
+    }
+
+    /// Whether every targeted order was cancelled
+    pub fn all_canceled(&self) -> bool {
+        self.not_canceled.is_empty()
+    }
+
+    /// `Ok` when `order_id` was cancelled, otherwise a typed error carrying
+    /// the exchange's reason
+    pub fn check_canceled(&self, order_id: &str) -> crate::errors::Result<()> {
+        use crate::errors::{OrderErrorKind, PolyError};
+
+        if self.is_canceled(order_id) {
+            return Ok(());
+        }
+        let reason = self
+            .not_canceled
+            .get(order_id)
+            .map(String::as_str)
+            .unwrap_or("order was not in the cancel response");
+        let kind = crate::decode::classify_order_error(reason)
+            .map(|(_, kind)| kind)
+            .unwrap_or(OrderErrorKind::CancellationFailed);
+        Err(PolyError::order(
+            format!("Failed to cancel order {}: {}", order_id, reason),
+            kind,
+        ))
+    }
+}
+
 /// Market information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Market {
@@ -876,6 +1131,15 @@ pub type OrderId = String;
 pub type MarketId = String;
 pub type ClientId = String;
 
+/// One page of a paginated listing
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub data: Vec<T>,
+    /// Cursor for the following page, `None` once the listing is exhausted.
+    /// Save it to resume pagination later.
+    pub next_cursor: Option<String>,
+}
+
 /// Parameters for querying open orders
 #[derive(Debug, Clone)]
 pub struct OpenOrderParams {
@@ -946,6 +1210,112 @@ impl TradeParams {
     }
 }
 
+/// Bucket width for `GET /prices-history`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceHistoryInterval {
+    OneMinute,
+    OneHour,
+    SixHours,
+    OneDay,
+    OneWeek,
+    Max,
+}
+
+impl PriceHistoryInterval {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PriceHistoryInterval::OneMinute => "1m",
+            PriceHistoryInterval::OneHour => "1h",
+            PriceHistoryInterval::SixHours => "6h",
+            PriceHistoryInterval::OneDay => "1d",
+            PriceHistoryInterval::OneWeek => "1w",
+            PriceHistoryInterval::Max => "max",
+        }
+    }
+}
+
+impl fmt::Display for PriceHistoryInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parameters for querying price history.
+///
+/// Use either `interval` (a window ending now) or `start_ts`/`end_ts`, not
+/// both. `fidelity` is the resolution in minutes.
+#[derive(Debug, Clone, Default)]
+pub struct PriceHistoryParams {
+    pub interval: Option<PriceHistoryInterval>,
+    pub start_ts: Option<u64>,
+    pub end_ts: Option<u64>,
+    pub fidelity: Option<u32>,
+}
+
+impl PriceHistoryParams {
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut params = Vec::with_capacity(4);
+
+        if let Some(x) = &self.interval {
+            params.push(("interval", x.to_string()));
+        }
+
+        if let Some(x) = &self.start_ts {
+            params.push(("startTs", x.to_string()));
+        }
+
+        if let Some(x) = &self.end_ts {
+            params.push(("endTs", x.to_string()));
+        }
+
+        if let Some(x) = &self.fidelity {
+            params.push(("fidelity", x.to_string()));
+        }
+        params
+    }
+
+    pub fn validate(&self) -> crate::errors::Result<()> {
+        use crate::errors::PolyError;
+
+        if self.interval.is_some() && (self.start_ts.is_some() || self.end_ts.is_some()) {
+            return Err(PolyError::validation(
+                "Price history takes either an interval or startTs/endTs, not both",
+            ));
+        }
+        if let (Some(start), Some(end)) = (self.start_ts, self.end_ts) {
+            if start > end {
+                return Err(PolyError::validation(format!(
+                    "Price history startTs {} is after endTs {}",
+                    start, end
+                )));
+            }
+        }
+        if self.fidelity == Some(0) {
+            return Err(PolyError::validation(
+                "Price history fidelity must be positive",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// One point of a price history series
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PricePoint {
+    /// Unix timestamp in seconds
+    #[serde(rename = "t")]
+    pub timestamp: u64,
+    #[serde(rename = "p")]
+    pub price: Decimal,
+}
+
+/// Response for `GET /prices-history`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceHistoryResponse {
+    #[serde(default)]
+    pub history: Vec<PricePoint>,
+}
+
 /// Open order information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenOrder {
@@ -987,7 +1357,8 @@ pub struct BalanceAllowance {
 pub struct BalanceAllowanceParams {
     pub asset_type: Option<AssetType>,
     pub token_id: Option<String>,
-    pub signature_type: Option<u8>,
+    /// Wallet to query; defaults to the client's signature type
+    pub signature_type: Option<crate::orders::SigType>,
 }
 
 impl BalanceAllowanceParams {
@@ -1002,13 +1373,13 @@ impl BalanceAllowanceParams {
             params.push(("token_id", x.to_string()));
         }
 
-        if let Some(x) = &self.signature_type {
-            params.push(("signature_type", x.to_string()));
+        if let Some(x) = self.signature_type {
+            params.push(("signature_type", (x as u8).to_string()));
         }
         params
     }
 
-    pub fn set_signature_type(&mut self, s: u8) {
+    pub fn set_signature_type(&mut self, s: crate::orders::SigType) {
         self.signature_type = Some(s);
     }
 }
@@ -1166,19 +1537,35 @@ pub struct SimplifiedMarket {
 }
 
 /// Common query parameters for Gamma API list endpoints
+///
+/// `Vec` fields are sent as repeated query keys (`slug=a&slug=b`).
 #[derive(Debug, Clone, Default)]
 pub struct GammaListParams {
     pub limit: Option<u32>,
     pub offset: Option<u32>,
+    pub id: Vec<String>,
+    pub slug: Vec<String>,
+    /// Markets only
+    pub clob_token_ids: Vec<String>,
+    /// Markets only
+    pub condition_ids: Vec<String>,
+    pub active: Option<bool>,
     pub closed: Option<bool>,
+    pub archived: Option<bool>,
     pub tag_id: Option<String>,
     pub exclude_tag_id: Option<String>,
-    pub related_tags: Option<String>,
+    /// Also match tags related to `tag_id`
+    pub related_tags: Option<bool>,
     pub order: Option<String>,
     pub ascending: Option<bool>,
     pub liquidity_num_min: Option<Decimal>,
-    pub end_date_max: Option<DateTime<Utc>>,
+    pub liquidity_num_max: Option<Decimal>,
+    pub volume_num_min: Option<Decimal>,
+    pub volume_num_max: Option<Decimal>,
     pub start_date_min: Option<DateTime<Utc>>,
+    pub start_date_max: Option<DateTime<Utc>>,
+    pub end_date_min: Option<DateTime<Utc>>,
+    pub end_date_max: Option<DateTime<Utc>>,
 }
 
 impl GammaListParams {
@@ -1186,6 +1573,15 @@ impl GammaListParams {
         Self::default()
     }
 
+    /// Whether the params name specific markets or events rather than
+    /// filtering the whole list
+    pub fn has_identifiers(&self) -> bool {
+        !(self.id.is_empty()
+            && self.slug.is_empty()
+            && self.clob_token_ids.is_empty()
+            && self.condition_ids.is_empty())
+    }
+
     pub fn to_query_params(&self) -> Vec<(&str, String)> {
         let mut params = Vec::with_capacity(8);
         if let Some(limit) = self.limit {
@@ -1194,17 +1590,35 @@ impl GammaListParams {
         if let Some(offset) = self.offset {
             params.push(("offset", offset.to_string()));
         }
+        for id in &self.id {
+            params.push(("id", id.clone()));
+        }
+        for slug in &self.slug {
+            params.push(("slug", slug.clone()));
+        }
+        for token_id in &self.clob_token_ids {
+            params.push(("clob_token_ids", token_id.clone()));
+        }
+        for condition_id in &self.condition_ids {
+            params.push(("condition_ids", condition_id.clone()));
+        }
+        if let Some(active) = self.active {
+            params.push(("active", active.to_string()));
+        }
         if let Some(closed) = self.closed {
             params.push(("closed", closed.to_string()));
         }
+        if let Some(archived) = self.archived {
+            params.push(("archived", archived.to_string()));
+        }
         if let Some(tag_id) = &self.tag_id {
             params.push(("tag_id", tag_id.clone()));
         }
         if let Some(exclude_tag_id) = &self.exclude_tag_id {
             params.push(("exclude_tag_id", exclude_tag_id.clone()));
         }
-        if let Some(related_tags) = &self.related_tags {
-            params.push(("related_tags", related_tags.clone()));
+        if let Some(related_tags) = self.related_tags {
+            params.push(("related_tags", related_tags.to_string()));
         }
         if let Some(order) = &self.order {
             params.push(("order", order.clone()));
@@ -1212,14 +1626,27 @@ impl GammaListParams {
         if let Some(ascending) = self.ascending {
             params.push(("ascending", ascending.to_string()));
         }
-        if let Some(liquidity_num_min) = &self.liquidity_num_min {
-            params.push(("liquidity_num_min", liquidity_num_min.to_string()));
-        }
-        if let Some(end_date_max) = &self.end_date_max {
-            params.push(("end_date_max", end_date_max.to_rfc3339()));
+        let numeric = [
+            ("liquidity_num_min", &self.liquidity_num_min),
+            ("liquidity_num_max", &self.liquidity_num_max),
+            ("volume_num_min", &self.volume_num_min),
+            ("volume_num_max", &self.volume_num_max),
+        ];
+        for (key, value) in numeric {
+            if let Some(value) = value {
+                params.push((key, value.to_string()));
+            }
         }
-        if let Some(start_date_min) = &self.start_date_min {
-            params.push(("start_date_min", start_date_min.to_rfc3339()));
+        let dates = [
+            ("start_date_min", &self.start_date_min),
+            ("start_date_max", &self.start_date_max),
+            ("end_date_min", &self.end_date_min),
+            ("end_date_max", &self.end_date_max),
+        ];
+        for (key, value) in dates {
+            if let Some(value) = value {
+                params.push((key, value.to_rfc3339()));
+            }
         }
         params
     }
@@ -1255,10 +1682,11 @@ impl DataApiPositionsParams {
 }
 
 /// Fields allowed for sorting the `/positions` response.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub enum DataApiSortBy {
     Current,
     Initial,
+    #[default]
     Tokens,
     CashPnl,
     PercentPnl,
@@ -1284,16 +1712,11 @@ impl DataApiSortBy {
     }
 }
 
-impl Default for DataApiSortBy {
-    fn default() -> Self {
-        DataApiSortBy::Tokens
-    }
-}
-
 /// Sort direction for the Data API `/positions` response.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub enum DataApiSortDirection {
     Asc,
+    #[default]
     Desc,
 }
 
@@ -1306,12 +1729,6 @@ impl DataApiSortDirection {
     }
 }
 
-impl Default for DataApiSortDirection {
-    fn default() -> Self {
-        DataApiSortDirection::Desc
-    }
-}
-
 /// A single row from the `/positions` endpoint.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DataPosition {
@@ -1368,83 +1785,768 @@ pub struct DataPositionValue {
     pub value: Decimal,
 }
 
-/// Gamma API event metadata
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GammaEvent {
-    #[serde(alias = "event_id")]
-    pub id: String,
-    pub slug: String,
-    pub name: Option<String>,
-    pub description: Option<String>,
-    pub active: Option<bool>,
-    pub closed: Option<bool>,
-    pub start_date_iso: Option<String>,
-    pub end_date_iso: Option<String>,
-    pub sport: Option<String>,
-    #[serde(default)]
-    pub tags: Vec<Tag>,
-    #[serde(default)]
-    pub markets: Vec<GammaEventMarket>,
-    #[serde(default)]
-    #[serde(flatten)]
-    pub metadata: serde_json::Value,
+/// How [`DataApiTradesParams::filter_amount`] is measured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataApiFilterType {
+    Cash,
+    Tokens,
 }
 
-/// Lightweight market info returned inside a Gamma event listing.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GammaEventMarket {
-    #[serde(rename = "conditionId")]
-    pub condition_id: String,
-    #[serde(rename = "id")]
-    #[serde(default)]
-    pub market_id: Option<String>,
-    #[serde(rename = "clobTokenIds")]
-    #[serde(default)]
-    pub clob_token_ids: Option<String>,
-    #[serde(default)]
-    pub slug: Option<String>,
+impl DataApiFilterType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DataApiFilterType::Cash => "CASH",
+            DataApiFilterType::Tokens => "TOKENS",
+        }
+    }
 }
 
-/// Tag metadata for Gamma API filtering
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Tag {
-    pub id: Option<String>,
-    pub slug: Option<String>,
-    pub name: Option<String>,
-    pub description: Option<String>,
-    #[serde(default)]
-    #[serde(flatten)]
-    pub metadata: serde_json::Value,
+fn push_csv(params: &mut Vec<(&'static str, String)>, key: &'static str, values: &[String]) {
+    if !values.is_empty() {
+        params.push((key, values.join(",")));
+    }
 }
 
-/// Sports metadata for Gamma API filtering
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Sport {
-    pub id: Option<String>,
-    pub name: Option<String>,
-    pub description: Option<String>,
-    #[serde(default)]
-    pub tag_ids: Vec<String>,
-    #[serde(default)]
-    pub tags: Vec<Tag>,
-    #[serde(default)]
-    #[serde(flatten)]
-    pub metadata: serde_json::Value,
+/// Parameters supported by the Data API `/trades` endpoint.
+#[derive(Debug, Clone, Default)]
+pub struct DataApiTradesParams {
+    /// Maximum number of rows to return.
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    /// Only return taker-side fills (the API default).
+    pub taker_only: Option<bool>,
+    /// Minimum trade size, measured per `filter_type`.
+    pub filter_amount: Option<Decimal>,
+    pub filter_type: Option<DataApiFilterType>,
+    /// Condition IDs to restrict to.
+    pub markets: Vec<String>,
+    pub event_id: Option<String>,
+    /// Wallet address to restrict to.
+    pub user: Option<String>,
+    pub side: Option<Side>,
 }
 
-/// Minimal Gamma market representation used for discovery
-#[derive(Debug, Clone, Deserialize)]
-pub struct GammaMarket {
+impl DataApiTradesParams {
+    pub fn to_query_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(limit) = self.limit {
+            params.push(("limit", limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            params.push(("offset", offset.to_string()));
+        }
+        if let Some(taker_only) = self.taker_only {
+            params.push(("takerOnly", taker_only.to_string()));
+        }
+        if let Some(amount) = self.filter_amount {
+            let filter_type = self.filter_type.unwrap_or(DataApiFilterType::Cash);
+            params.push(("filterType", filter_type.as_str().to_string()));
+            params.push(("filterAmount", amount.to_string()));
+        }
+        push_csv(&mut params, "market", &self.markets);
+        if let Some(event_id) = &self.event_id {
+            params.push(("eventId", event_id.clone()));
+        }
+        if let Some(user) = &self.user {
+            params.push(("user", user.clone()));
+        }
+        if let Some(side) = self.side {
+            params.push(("side", side.as_str().to_string()));
+        }
+        params
+    }
+}
+
+/// A single row from the `/trades` endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataTrade {
+    #[serde(rename = "proxyWallet")]
+    pub proxy_wallet: String,
+    pub side: Side,
+    pub asset: String,
     #[serde(rename = "conditionId")]
     pub condition_id: String,
-    pub slug: String,
-    pub question: Option<String>,
-    pub description: Option<String>,
-    pub category: Option<String>,
-    pub active: bool,
-    pub closed: bool,
-    pub outcomes: Option<String>,
-    #[serde(rename = "clobTokenIds")]
+    pub size: Decimal,
+    pub price: Decimal,
+    pub timestamp: i64,
+    pub title: Option<String>,
+    pub slug: Option<String>,
+    #[serde(rename = "eventSlug")]
+    pub event_slug: Option<String>,
+    pub outcome: Option<String>,
+    #[serde(rename = "outcomeIndex")]
+    pub outcome_index: Option<u32>,
+    pub name: Option<String>,
+    pub pseudonym: Option<String>,
+    #[serde(rename = "transactionHash")]
+    pub transaction_hash: Option<String>,
+}
+
+/// Kind of on-chain activity reported by `/activity`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DataActivityType {
+    Trade,
+    Split,
+    Merge,
+    Redeem,
+    Reward,
+    Conversion,
+    /// An activity type this crate does not know about yet
+    #[serde(other)]
+    Unknown,
+}
+
+impl DataActivityType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DataActivityType::Trade => "TRADE",
+            DataActivityType::Split => "SPLIT",
+            DataActivityType::Merge => "MERGE",
+            DataActivityType::Redeem => "REDEEM",
+            DataActivityType::Reward => "REWARD",
+            DataActivityType::Conversion => "CONVERSION",
+            DataActivityType::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+/// Fields allowed for sorting the `/activity` response.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum DataActivitySortBy {
+    #[default]
+    Timestamp,
+    Tokens,
+    Cash,
+}
+
+impl DataActivitySortBy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DataActivitySortBy::Timestamp => "TIMESTAMP",
+            DataActivitySortBy::Tokens => "TOKENS",
+            DataActivitySortBy::Cash => "CASH",
+        }
+    }
+}
+
+/// Parameters supported by the Data API `/activity` endpoint.
+#[derive(Debug, Clone, Default)]
+pub struct DataApiActivityParams {
+    /// Maximum number of rows to return.
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    /// Condition IDs to restrict to.
+    pub markets: Vec<String>,
+    pub event_id: Option<String>,
+    /// Activity kinds to include; all when empty.
+    pub types: Vec<DataActivityType>,
+    /// Earliest unix timestamp (seconds) to include.
+    pub start: Option<i64>,
+    /// Latest unix timestamp (seconds) to include.
+    pub end: Option<i64>,
+    pub side: Option<Side>,
+    pub sort_by: Option<DataActivitySortBy>,
+    pub sort_direction: Option<DataApiSortDirection>,
+}
+
+impl DataApiActivityParams {
+    pub fn to_query_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(limit) = self.limit {
+            params.push(("limit", limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            params.push(("offset", offset.to_string()));
+        }
+        push_csv(&mut params, "market", &self.markets);
+        if let Some(event_id) = &self.event_id {
+            params.push(("eventId", event_id.clone()));
+        }
+        if !self.types.is_empty() {
+            let types: Vec<_> = self.types.iter().map(|t| t.as_str()).collect();
+            params.push(("type", types.join(",")));
+        }
+        if let Some(start) = self.start {
+            params.push(("start", start.to_string()));
+        }
+        if let Some(end) = self.end {
+            params.push(("end", end.to_string()));
+        }
+        if let Some(side) = self.side {
+            params.push(("side", side.as_str().to_string()));
+        }
+        if let Some(sort_by) = self.sort_by {
+            params.push(("sortBy", sort_by.as_str().to_string()));
+        }
+        if let Some(sort_direction) = self.sort_direction {
+            params.push(("sortDirection", sort_direction.as_str().to_string()));
+        }
+        params
+    }
+}
+
+/// A single row from the `/activity` endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataActivity {
+    #[serde(rename = "proxyWallet")]
+    pub proxy_wallet: String,
+    pub timestamp: i64,
+    #[serde(rename = "conditionId")]
+    pub condition_id: Option<String>,
+    #[serde(rename = "type")]
+    pub activity_type: DataActivityType,
+    pub size: Decimal,
+    #[serde(rename = "usdcSize")]
+    pub usdc_size: Decimal,
+    #[serde(rename = "transactionHash")]
+    pub transaction_hash: Option<String>,
+    pub price: Option<Decimal>,
+    pub asset: Option<String>,
+    pub side: Option<Side>,
+    #[serde(rename = "outcomeIndex")]
+    pub outcome_index: Option<u32>,
+    pub title: Option<String>,
+    pub slug: Option<String>,
+    #[serde(rename = "eventSlug")]
+    pub event_slug: Option<String>,
+    pub outcome: Option<String>,
+}
+
+/// Parameters supported by the Data API `/holders` endpoint.
+#[derive(Debug, Clone, Default)]
+pub struct DataApiHoldersParams {
+    /// Maximum number of holders per token.
+    pub limit: Option<u32>,
+    /// Minimum balance to include.
+    pub min_balance: Option<Decimal>,
+}
+
+impl DataApiHoldersParams {
+    pub fn to_query_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(limit) = self.limit {
+            params.push(("limit", limit.to_string()));
+        }
+        if let Some(min_balance) = self.min_balance {
+            params.push(("minBalance", min_balance.to_string()));
+        }
+        params
+    }
+}
+
+/// Top holders of one outcome token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataTokenHolders {
+    pub token: String,
+    #[serde(default)]
+    pub holders: Vec<DataHolder>,
+}
+
+/// A single holder returned by `/holders`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataHolder {
+    #[serde(rename = "proxyWallet")]
+    pub proxy_wallet: String,
+    pub asset: Option<String>,
+    pub amount: Decimal,
+    #[serde(rename = "outcomeIndex")]
+    pub outcome_index: Option<u32>,
+    pub name: Option<String>,
+    pub pseudonym: Option<String>,
+}
+
+/// Open interest for one market, from `/oi`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataOpenInterest {
+    pub market: String,
+    pub value: Decimal,
+}
+
+/// Live volume of an event, from `/live-volume`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataLiveVolume {
+    pub total: Decimal,
+    /// Per-market breakdown; `value` is the market's volume.
+    #[serde(default)]
+    pub markets: Vec<DataOpenInterest>,
+}
+
+/// Leaderboard ranking category.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum LeaderboardCategory {
+    #[default]
+    Overall,
+    Politics,
+    Sports,
+    Crypto,
+    Culture,
+    Mentions,
+    Weather,
+    Economics,
+    Tech,
+    Finance,
+}
+
+impl LeaderboardCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LeaderboardCategory::Overall => "OVERALL",
+            LeaderboardCategory::Politics => "POLITICS",
+            LeaderboardCategory::Sports => "SPORTS",
+            LeaderboardCategory::Crypto => "CRYPTO",
+            LeaderboardCategory::Culture => "CULTURE",
+            LeaderboardCategory::Mentions => "MENTIONS",
+            LeaderboardCategory::Weather => "WEATHER",
+            LeaderboardCategory::Economics => "ECONOMICS",
+            LeaderboardCategory::Tech => "TECH",
+            LeaderboardCategory::Finance => "FINANCE",
+        }
+    }
+}
+
+/// Window the leaderboard is computed over.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum LeaderboardPeriod {
+    #[default]
+    Day,
+    Week,
+    Month,
+    All,
+}
+
+impl LeaderboardPeriod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LeaderboardPeriod::Day => "DAY",
+            LeaderboardPeriod::Week => "WEEK",
+            LeaderboardPeriod::Month => "MONTH",
+            LeaderboardPeriod::All => "ALL",
+        }
+    }
+}
+
+/// Leaderboard ranking metric.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum LeaderboardOrderBy {
+    #[default]
+    Pnl,
+    Volume,
+}
+
+impl LeaderboardOrderBy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LeaderboardOrderBy::Pnl => "PNL",
+            LeaderboardOrderBy::Volume => "VOL",
+        }
+    }
+}
+
+/// Parameters supported by the Data API `/v1/leaderboard` endpoint.
+#[derive(Debug, Clone, Default)]
+pub struct DataApiLeaderboardParams {
+    pub category: Option<LeaderboardCategory>,
+    pub time_period: Option<LeaderboardPeriod>,
+    pub order_by: Option<LeaderboardOrderBy>,
+    /// Maximum number of rows to return (the API caps this at 50).
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    /// Only return the entry for this wallet.
+    pub user: Option<String>,
+    /// Only return the entry for this username.
+    pub user_name: Option<String>,
+}
+
+impl DataApiLeaderboardParams {
+    pub fn to_query_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            (
+                "category",
+                self.category.unwrap_or_default().as_str().to_string(),
+            ),
+            (
+                "timePeriod",
+                self.time_period.unwrap_or_default().as_str().to_string(),
+            ),
+            (
+                "orderBy",
+                self.order_by.unwrap_or_default().as_str().to_string(),
+            ),
+        ];
+        if let Some(limit) = self.limit {
+            params.push(("limit", limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            params.push(("offset", offset.to_string()));
+        }
+        if let Some(user) = &self.user {
+            params.push(("user", user.clone()));
+        }
+        if let Some(user_name) = &self.user_name {
+            params.push(("userName", user_name.clone()));
+        }
+        params
+    }
+}
+
+/// A single row from the leaderboard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    /// Returned as a string by the API
+    pub rank: String,
+    #[serde(rename = "proxyWallet")]
+    pub proxy_wallet: String,
+    #[serde(rename = "userName")]
+    pub user_name: Option<String>,
+    #[serde(rename = "vol")]
+    pub volume: Decimal,
+    pub pnl: Decimal,
+    #[serde(rename = "xUsername")]
+    pub x_username: Option<String>,
+    #[serde(rename = "verifiedBadge")]
+    pub verified_badge: Option<bool>,
+}
+
+/// Gamma API event metadata
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GammaEvent {
+    #[serde(alias = "event_id")]
+    pub id: String,
+    pub slug: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub active: Option<bool>,
+    pub closed: Option<bool>,
+    pub start_date_iso: Option<String>,
+    pub end_date_iso: Option<String>,
+    pub sport: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+    #[serde(default)]
+    pub markets: Vec<GammaEventMarket>,
+    #[serde(default)]
+    #[serde(flatten)]
+    pub metadata: serde_json::Value,
+}
+
+/// Lightweight market info returned inside a Gamma event listing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GammaEventMarket {
+    #[serde(rename = "conditionId")]
+    pub condition_id: String,
+    #[serde(rename = "id")]
+    #[serde(default)]
+    pub market_id: Option<String>,
+    #[serde(rename = "clobTokenIds")]
+    #[serde(default)]
+    pub clob_token_ids: Option<String>,
+    #[serde(default)]
+    pub slug: Option<String>,
+}
+
+/// Tag metadata for Gamma API filtering
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tag {
+    pub id: Option<String>,
+    pub slug: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    #[serde(flatten)]
+    pub metadata: serde_json::Value,
+}
+
+/// Public profile of a Polymarket user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GammaProfile {
+    #[serde(rename = "proxyWallet", default)]
+    pub proxy_wallet: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub pseudonym: Option<String>,
+    #[serde(default)]
+    pub bio: Option<String>,
+    #[serde(rename = "profileImage", default)]
+    pub profile_image: Option<String>,
+    #[serde(rename = "xUsername", default)]
+    pub x_username: Option<String>,
+    #[serde(rename = "verifiedBadge", default)]
+    pub verified_badge: Option<bool>,
+    #[serde(rename = "createdAt", default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    #[serde(flatten)]
+    pub metadata: serde_json::Value,
+}
+
+/// A recurring series of Gamma events
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GammaSeries {
+    pub id: String,
+    pub slug: Option<String>,
+    pub title: Option<String>,
+    #[serde(rename = "seriesType", default)]
+    pub series_type: Option<String>,
+    #[serde(default)]
+    pub recurrence: Option<String>,
+    pub active: Option<bool>,
+    pub closed: Option<bool>,
+    #[serde(default)]
+    #[serde(flatten)]
+    pub metadata: serde_json::Value,
+}
+
+/// Comment on a Gamma event, series or market
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GammaComment {
+    pub id: String,
+    pub body: Option<String>,
+    #[serde(rename = "parentEntityType", default)]
+    pub parent_entity_type: Option<String>,
+    #[serde(rename = "parentEntityID", default)]
+    pub parent_entity_id: Option<u64>,
+    /// Set on replies
+    #[serde(rename = "parentCommentID", default)]
+    pub parent_comment_id: Option<String>,
+    #[serde(rename = "userAddress", default)]
+    pub user_address: Option<String>,
+    #[serde(rename = "createdAt", default)]
+    pub created_at: Option<String>,
+    #[serde(rename = "reactionCount", default)]
+    pub reaction_count: Option<u64>,
+    #[serde(default)]
+    pub profile: Option<GammaProfile>,
+    #[serde(default)]
+    #[serde(flatten)]
+    pub metadata: serde_json::Value,
+}
+
+/// Kind of entity a Gamma comment thread hangs off
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentEntityType {
+    Event,
+    Series,
+    Market,
+}
+
+impl CommentEntityType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommentEntityType::Event => "Event",
+            CommentEntityType::Series => "Series",
+            CommentEntityType::Market => "market",
+        }
+    }
+}
+
+/// Pagination block returned by Gamma search
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GammaPagination {
+    #[serde(rename = "hasMore", default)]
+    pub has_more: bool,
+    #[serde(rename = "totalResults", default)]
+    pub total_results: Option<u64>,
+}
+
+/// Results of a Gamma public search
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct GammaSearchResults {
+    #[serde(default)]
+    pub events: Vec<GammaEvent>,
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+    #[serde(default)]
+    pub profiles: Vec<GammaProfile>,
+    #[serde(default)]
+    pub pagination: GammaPagination,
+}
+
+/// Parameters for the Gamma `/public-search` endpoint
+#[derive(Debug, Clone, Default)]
+pub struct GammaSearchParams {
+    pub q: String,
+    pub limit_per_type: Option<u32>,
+    pub page: Option<u32>,
+    /// e.g. `active`, `resolved`
+    pub events_status: Option<String>,
+    pub events_tag: Vec<String>,
+    pub sort: Option<String>,
+    pub ascending: Option<bool>,
+    pub search_tags: Option<bool>,
+    pub search_profiles: Option<bool>,
+}
+
+impl GammaSearchParams {
+    pub fn new(q: impl Into<String>) -> Self {
+        Self {
+            q: q.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut params = vec![("q", self.q.clone())];
+        if let Some(limit_per_type) = self.limit_per_type {
+            params.push(("limit_per_type", limit_per_type.to_string()));
+        }
+        if let Some(page) = self.page {
+            params.push(("page", page.to_string()));
+        }
+        if let Some(events_status) = &self.events_status {
+            params.push(("events_status", events_status.clone()));
+        }
+        for tag in &self.events_tag {
+            params.push(("events_tag", tag.clone()));
+        }
+        if let Some(sort) = &self.sort {
+            params.push(("sort", sort.clone()));
+        }
+        if let Some(ascending) = self.ascending {
+            params.push(("ascending", ascending.to_string()));
+        }
+        if let Some(search_tags) = self.search_tags {
+            params.push(("search_tags", search_tags.to_string()));
+        }
+        if let Some(search_profiles) = self.search_profiles {
+            params.push(("search_profiles", search_profiles.to_string()));
+        }
+        params
+    }
+}
+
+/// Parameters for the Gamma `/series` endpoint
+#[derive(Debug, Clone, Default)]
+pub struct GammaSeriesParams {
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    pub order: Option<String>,
+    pub ascending: Option<bool>,
+    pub slug: Vec<String>,
+    pub categories_ids: Vec<String>,
+    pub categories_labels: Vec<String>,
+    pub closed: Option<bool>,
+    pub recurrence: Option<String>,
+}
+
+impl GammaSeriesParams {
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut params = Vec::with_capacity(8);
+        if let Some(limit) = self.limit {
+            params.push(("limit", limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            params.push(("offset", offset.to_string()));
+        }
+        if let Some(order) = &self.order {
+            params.push(("order", order.clone()));
+        }
+        if let Some(ascending) = self.ascending {
+            params.push(("ascending", ascending.to_string()));
+        }
+        for slug in &self.slug {
+            params.push(("slug", slug.clone()));
+        }
+        for id in &self.categories_ids {
+            params.push(("categories_ids", id.clone()));
+        }
+        for label in &self.categories_labels {
+            params.push(("categories_labels", label.clone()));
+        }
+        if let Some(closed) = self.closed {
+            params.push(("closed", closed.to_string()));
+        }
+        if let Some(recurrence) = &self.recurrence {
+            params.push(("recurrence", recurrence.clone()));
+        }
+        params
+    }
+}
+
+/// Parameters for the Gamma `/comments` endpoints
+#[derive(Debug, Clone, Default)]
+pub struct GammaCommentParams {
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    pub order: Option<String>,
+    pub ascending: Option<bool>,
+    pub parent_entity_type: Option<CommentEntityType>,
+    pub parent_entity_id: Option<u64>,
+    /// Include the commenter's positions in the parent market
+    pub get_positions: Option<bool>,
+    /// Only return comments from users holding a position
+    pub holders_only: Option<bool>,
+}
+
+impl GammaCommentParams {
+    /// Comments on one event, series or market
+    pub fn for_entity(entity_type: CommentEntityType, entity_id: u64) -> Self {
+        Self {
+            parent_entity_type: Some(entity_type),
+            parent_entity_id: Some(entity_id),
+            ..Default::default()
+        }
+    }
+
+    pub fn to_query_params(&self) -> Vec<(&str, String)> {
+        let mut params = Vec::with_capacity(8);
+        if let Some(limit) = self.limit {
+            params.push(("limit", limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            params.push(("offset", offset.to_string()));
+        }
+        if let Some(order) = &self.order {
+            params.push(("order", order.clone()));
+        }
+        if let Some(ascending) = self.ascending {
+            params.push(("ascending", ascending.to_string()));
+        }
+        if let Some(entity_type) = self.parent_entity_type {
+            params.push(("parent_entity_type", entity_type.as_str().to_string()));
+        }
+        if let Some(entity_id) = self.parent_entity_id {
+            params.push(("parent_entity_id", entity_id.to_string()));
+        }
+        if let Some(get_positions) = self.get_positions {
+            params.push(("get_positions", get_positions.to_string()));
+        }
+        if let Some(holders_only) = self.holders_only {
+            params.push(("holders_only", holders_only.to_string()));
+        }
+        params
+    }
+}
+
+/// Sports metadata for Gamma API filtering
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sport {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub tag_ids: Vec<String>,
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+    #[serde(default)]
+    #[serde(flatten)]
+    pub metadata: serde_json::Value,
+}
+
+/// Minimal Gamma market representation used for discovery
+#[derive(Debug, Clone, Deserialize)]
+pub struct GammaMarket {
+    #[serde(rename = "conditionId")]
+    pub condition_id: String,
+    pub slug: String,
+    pub question: Option<String>,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    pub active: bool,
+    pub closed: bool,
+    pub outcomes: Option<String>,
+    #[serde(rename = "clobTokenIds")]
     pub clob_token_ids: Option<String>,
     pub icon: Option<String>,
     #[serde(rename = "endDate")]
@@ -1509,6 +2611,65 @@ pub struct Rewards {
     pub reward_epoch: Option<Decimal>,
 }
 
+/// One reward program attached to a market
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewardsConfig {
+    #[serde(default)]
+    pub asset_address: String,
+    #[serde(default)]
+    pub start_date: Option<String>,
+    #[serde(default)]
+    pub end_date: Option<String>,
+    /// Daily reward pool, in units of `asset_address`
+    #[serde(default)]
+    pub rate_per_day: Decimal,
+    #[serde(default)]
+    pub total_rewards: Decimal,
+}
+
+/// Market currently eligible for liquidity rewards
+/// (`GET /rewards/markets/current`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewardsMarket {
+    pub condition_id: String,
+    #[serde(default)]
+    pub rewards_config: Vec<RewardsConfig>,
+    /// Maximum distance from the midpoint, in cents
+    #[serde(default)]
+    pub rewards_max_spread: Decimal,
+    /// Minimum order size, in shares
+    #[serde(default)]
+    pub rewards_min_size: Decimal,
+}
+
+/// A user's reward earnings in one market on one day (`GET /rewards/user`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserEarning {
+    pub date: String,
+    #[serde(default)]
+    pub condition_id: String,
+    #[serde(default)]
+    pub asset_address: String,
+    #[serde(default)]
+    pub maker_address: String,
+    pub earnings: Decimal,
+    #[serde(default)]
+    pub asset_rate: Decimal,
+}
+
+/// A user's total reward earnings on one day (`GET /rewards/user/total`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TotalUserEarning {
+    pub date: String,
+    #[serde(default)]
+    pub asset_address: String,
+    #[serde(default)]
+    pub maker_address: String,
+    pub earnings: Decimal,
+    #[serde(default)]
+    pub asset_rate: Decimal,
+}
+
 // For compatibility with reference implementation
 pub type ClientResult<T> = anyhow::Result<T>;
 
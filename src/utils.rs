@@ -20,6 +20,7 @@ type HmacSha256 = Hmac<Sha256>;
 /// High-precision timestamp utilities
 pub mod time {
     use super::*;
+    use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
 
     /// Get current Unix timestamp in seconds
     #[inline]
@@ -68,6 +69,88 @@ pub mod time {
     pub fn secs_to_datetime(timestamp: u64) -> DateTime<Utc> {
         DateTime::from_timestamp(timestamp as i64, 0).unwrap_or(Utc::now())
     }
+
+    /// One round trip against the server's clock
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct ClockSample {
+        /// Local time just before the request was sent
+        pub sent_millis: u64,
+        /// Local time once the response arrived
+        pub received_millis: u64,
+        /// Server time reported in the response, in whole seconds
+        pub server_secs: u64,
+    }
+
+    impl ClockSample {
+        pub fn round_trip(&self) -> Duration {
+            Duration::from_millis(self.received_millis.saturating_sub(self.sent_millis))
+        }
+
+        /// Estimated `server - local` offset in milliseconds.
+        ///
+        /// The server truncates to seconds, so its clock is assumed to sit in
+        /// the middle of the reported second, and the reading is assumed to
+        /// have been taken halfway through the round trip.
+        pub fn offset_millis(&self) -> i64 {
+            let server_millis = self.server_secs as i64 * 1000 + 500;
+            let local_midpoint = (self.sent_millis + self.received_millis) as i64 / 2;
+            server_millis - local_midpoint
+        }
+    }
+
+    /// Local clock corrected by the last estimated offset to the server.
+    ///
+    /// Starts with no offset, so it behaves like the local clock until a
+    /// sample is recorded.
+    #[derive(Debug, Default)]
+    pub struct ServerClock {
+        offset_millis: AtomicI64,
+        round_trip_millis: AtomicU64,
+        synced: AtomicBool,
+    }
+
+    impl ServerClock {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Adopt the sample with the shortest round trip, which bounds the
+        /// offset error most tightly
+        pub fn record(&self, samples: &[ClockSample]) -> Option<ClockSample> {
+            let best = *samples.iter().min_by_key(|s| s.round_trip())?;
+            self.offset_millis
+                .store(best.offset_millis(), Ordering::Relaxed);
+            self.round_trip_millis
+                .store(best.round_trip().as_millis() as u64, Ordering::Relaxed);
+            self.synced.store(true, Ordering::Relaxed);
+            Some(best)
+        }
+
+        /// Estimated `server - local` offset in milliseconds
+        pub fn offset_millis(&self) -> i64 {
+            self.offset_millis.load(Ordering::Relaxed)
+        }
+
+        /// Round trip of the sample the offset was taken from
+        pub fn round_trip(&self) -> Duration {
+            Duration::from_millis(self.round_trip_millis.load(Ordering::Relaxed))
+        }
+
+        /// Whether at least one sample has been recorded
+        pub fn is_synced(&self) -> bool {
+            self.synced.load(Ordering::Relaxed)
+        }
+
+        /// Estimated server time in milliseconds
+        pub fn now_millis(&self) -> u64 {
+            now_millis().saturating_add_signed(self.offset_millis())
+        }
+
+        /// Estimated server time in seconds
+        pub fn now_secs(&self) -> u64 {
+            self.now_millis() / 1000
+        }
+    }
 }
 
 /// Cryptographic utilities for signing and authentication
@@ -349,43 +432,55 @@ pub mod retry {
         }
     }
 
+    impl RetryConfig {
+        /// Backoff before retry number `attempt` (zero-based), capped at
+        /// `max_delay` and randomised by ±5% when jitter is enabled
+        pub fn backoff_delay(&self, attempt: usize) -> Duration {
+            let factor = self.backoff_factor.powi(attempt as i32);
+            let delay = Duration::from_secs_f64(
+                (self.initial_delay.as_secs_f64() * factor).min(self.max_delay.as_secs_f64()),
+            );
+
+            if self.jitter {
+                let jitter_factor = rand::random::<f64>() * 0.1; // ±5%
+                let jitter = 1.0 + (jitter_factor - 0.05);
+                Duration::from_nanos((delay.as_nanos() as f64 * jitter) as u64)
+            } else {
+                delay
+            }
+        }
+    }
+
     /// Retry a future with exponential backoff
+    ///
+    /// A [`PolyError::RateLimit`] carrying a `retry_after` hint waits at
+    /// least that long before the next attempt.
     pub async fn with_retry<F, Fut, T>(config: &RetryConfig, mut operation: F) -> Result<T>
     where
         F: FnMut() -> Fut,
         Fut: Future<Output = Result<T>>,
     {
-        let mut delay = config.initial_delay;
         let mut last_error = None;
 
         for attempt in 0..config.max_attempts {
             match operation().await {
                 Ok(result) => return Ok(result),
                 Err(err) => {
-                    last_error = Some(err.clone());
-
                     if !err.is_retryable() || attempt == config.max_attempts - 1 {
                         return Err(err);
                     }
 
-                    // Add jitter if enabled
-                    let actual_delay = if config.jitter {
-                        let jitter_factor = rand::random::<f64>() * 0.1; // ±10%
-                        let jitter = 1.0 + (jitter_factor - 0.05);
-                        Duration::from_nanos((delay.as_nanos() as f64 * jitter) as u64)
-                    } else {
-                        delay
-                    };
-
-                    sleep(actual_delay).await;
-
-                    // Exponential backoff
-                    delay = std::cmp::min(
-                        Duration::from_nanos(
-                            (delay.as_nanos() as f64 * config.backoff_factor) as u64,
-                        ),
-                        config.max_delay,
-                    );
+                    let mut delay = config.backoff_delay(attempt);
+                    if let PolyError::RateLimit {
+                        retry_after: Some(retry_after),
+                        ..
+                    } = &err
+                    {
+                        delay = delay.max(*retry_after);
+                    }
+                    last_error = Some(err);
+
+                    sleep(delay).await;
                 }
             }
         }
@@ -393,7 +488,7 @@ pub mod retry {
         Err(last_error.unwrap_or_else(|| {
             PolyError::internal(
                 "Retry loop failed",
-                std::io::Error::new(std::io::ErrorKind::Other, "No error captured"),
+                std::io::Error::other("No error captured"),
             )
         }))
     }
@@ -457,52 +552,259 @@ pub mod url {
 /// Rate limiting utilities
 pub mod rate_limit {
     use super::*;
-    use std::sync::{Arc, Mutex};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use std::time::Instant;
+
+    /// Counters describing how much a limiter has throttled callers
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct RateLimitStats {
+        /// Tokens handed out
+        pub requests: u64,
+        /// Requests that had to wait for a token
+        pub delayed_requests: u64,
+        /// Cumulative time spent waiting
+        pub total_delay: Duration,
+        /// Longest single wait
+        pub max_delay: Duration,
+    }
+
+    impl RateLimitStats {
+        /// Average wait across all requests
+        pub fn average_delay(&self) -> Duration {
+            if self.requests == 0 {
+                Duration::ZERO
+            } else {
+                self.total_delay / self.requests as u32
+            }
+        }
+
+        fn record(&mut self, delay: Duration, delayed: bool) {
+            self.requests += 1;
+            if delayed {
+                self.delayed_requests += 1;
+                self.total_delay += delay;
+                self.max_delay = self.max_delay.max(delay);
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    struct BucketState {
+        tokens: usize,
+        last_refill: Instant,
+        stats: RateLimitStats,
+    }
 
     /// Simple token bucket rate limiter
     #[derive(Debug)]
     pub struct TokenBucket {
         capacity: usize,
-        tokens: Arc<Mutex<usize>>,
         refill_rate: Duration,
-        last_refill: Arc<Mutex<SystemTime>>,
+        state: Mutex<BucketState>,
     }
 
     impl TokenBucket {
+        /// # Panics
+        ///
+        /// If the limit fails [`RateLimit::validate`].
         pub fn new(capacity: usize, refill_per_second: usize) -> Self {
+            if let Err(err) = RateLimit::new(capacity, refill_per_second).validate() {
+                panic!("{}", err);
+            }
             Self {
                 capacity,
-                tokens: Arc::new(Mutex::new(capacity)),
                 refill_rate: Duration::from_secs(1) / refill_per_second as u32,
-                last_refill: Arc::new(Mutex::new(SystemTime::now())),
+                state: Mutex::new(BucketState {
+                    tokens: capacity,
+                    last_refill: Instant::now(),
+                    stats: RateLimitStats::default(),
+                }),
             }
         }
 
         /// Try to consume a token, return true if successful
         pub fn try_consume(&self) -> bool {
-            self.refill();
+            let mut state = self.state.lock().unwrap();
+            self.refill(&mut state);
 
-            let mut tokens = self.tokens.lock().unwrap();
-            if *tokens > 0 {
-                *tokens -= 1;
+            if state.tokens > 0 {
+                state.tokens -= 1;
+                state.stats.record(Duration::ZERO, false);
                 true
             } else {
                 false
             }
         }
 
-        fn refill(&self) {
-            let now = SystemTime::now();
-            let mut last_refill = self.last_refill.lock().unwrap();
-            let elapsed = now.duration_since(*last_refill).unwrap_or_default();
+        /// Wait until a token is available and consume it.
+        ///
+        /// Returns how long the caller was delayed.
+        pub async fn acquire(&self) -> Duration {
+            let start = Instant::now();
+            let mut delayed = false;
+
+            loop {
+                let wait = {
+                    let mut state = self.state.lock().unwrap();
+                    self.refill(&mut state);
+
+                    if state.tokens > 0 {
+                        state.tokens -= 1;
+                        let delay = start.elapsed();
+                        state.stats.record(delay, delayed);
+                        return if delayed { delay } else { Duration::ZERO };
+                    }
+
+                    self.refill_rate.saturating_sub(state.last_refill.elapsed())
+                };
+
+                delayed = true;
+                tokio::time::sleep(wait).await;
+            }
+        }
+
+        /// Snapshot of the delay metrics collected so far
+        pub fn stats(&self) -> RateLimitStats {
+            self.state.lock().unwrap().stats
+        }
+
+        fn refill(&self, state: &mut BucketState) {
+            let elapsed = state.last_refill.elapsed();
 
             if elapsed >= self.refill_rate {
-                let tokens_to_add = elapsed.as_nanos() / self.refill_rate.as_nanos();
-                let mut tokens = self.tokens.lock().unwrap();
-                *tokens = std::cmp::min(self.capacity, *tokens + tokens_to_add as usize);
-                *last_refill = now;
+                let tokens_to_add = (elapsed.as_nanos() / self.refill_rate.as_nanos()) as usize;
+                state.tokens = std::cmp::min(self.capacity, state.tokens + tokens_to_add);
+                // Carry over the partial interval so slow polling does not lose tokens
+                state.last_refill += self.refill_rate * tokens_to_add as u32;
+            }
+        }
+    }
+
+    /// Endpoint groups that share a rate limit
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum EndpointFamily {
+        /// Order book, price, midpoint, spread and tick size reads
+        MarketData,
+        /// `POST /order` and `POST /orders`
+        OrderPosting,
+        /// Single, batch, market and cancel-all cancellations
+        Cancels,
+        /// `POST /v1/heartbeats`, kept apart so heartbeats never use up the
+        /// budget an emergency cancel needs
+        Heartbeats,
+        /// Authenticated, cursor-paginated account reads: open orders,
+        /// trades and reward earnings
+        AccountData,
+        /// Gamma API requests
+        Gamma,
+        /// Data API requests
+        DataApi,
+    }
+
+    /// Burst capacity and sustained rate for one endpoint family
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct RateLimit {
+        pub capacity: usize,
+        pub refill_per_second: usize,
+    }
+
+    impl RateLimit {
+        pub fn new(capacity: usize, refill_per_second: usize) -> Self {
+            Self {
+                capacity,
+                refill_per_second,
             }
         }
+
+        /// Reject limits a token bucket cannot enforce: no capacity, no
+        /// refill, or a refill interval below one nanosecond
+        pub fn validate(&self) -> Result<()> {
+            self.check().map_err(PolyError::config)
+        }
+
+        fn check(&self) -> std::result::Result<(), String> {
+            if self.capacity == 0 {
+                return Err("capacity must be positive".to_string());
+            }
+            if self.refill_per_second == 0 || self.refill_per_second > 1_000_000_000 {
+                return Err(format!(
+                    "refill must be between 1 and 1e9 per second, got {}",
+                    self.refill_per_second
+                ));
+            }
+            Ok(())
+        }
+    }
+
+    /// Per-family rate limits; families without a limit are not throttled
+    #[derive(Debug, Clone, Default)]
+    pub struct RateLimitConfig {
+        limits: HashMap<EndpointFamily, RateLimit>,
+    }
+
+    impl RateLimitConfig {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Set the limit for an endpoint family
+        pub fn with_limit(mut self, family: EndpointFamily, limit: RateLimit) -> Self {
+            self.limits.insert(family, limit);
+            self
+        }
+
+        pub fn limit(&self, family: EndpointFamily) -> Option<RateLimit> {
+            self.limits.get(&family).copied()
+        }
+
+        /// Check every configured limit with [`RateLimit::validate`]
+        pub fn validate(&self) -> Result<()> {
+            for (family, limit) in &self.limits {
+                limit.check().map_err(|err| {
+                    PolyError::config(format!("Invalid {:?} rate limit: {}", family, err))
+                })?;
+            }
+            Ok(())
+        }
+    }
+
+    /// A set of token buckets keyed by endpoint family
+    #[derive(Debug, Default)]
+    pub struct RateLimiter {
+        buckets: HashMap<EndpointFamily, TokenBucket>,
+    }
+
+    impl RateLimiter {
+        /// # Panics
+        ///
+        /// If `config` fails [`RateLimitConfig::validate`].
+        pub fn new(config: &RateLimitConfig) -> Self {
+            let buckets = config
+                .limits
+                .iter()
+                .map(|(family, limit)| {
+                    (
+                        *family,
+                        TokenBucket::new(limit.capacity, limit.refill_per_second),
+                    )
+                })
+                .collect();
+            Self { buckets }
+        }
+
+        /// Wait for capacity in the given family, returning the delay
+        pub async fn acquire(&self, family: EndpointFamily) -> Duration {
+            match self.buckets.get(&family) {
+                Some(bucket) => bucket.acquire().await,
+                None => Duration::ZERO,
+            }
+        }
+
+        /// Delay metrics for a family, if it is rate limited
+        pub fn stats(&self, family: EndpointFamily) -> Option<RateLimitStats> {
+            self.buckets.get(&family).map(TokenBucket::stats)
+        }
     }
 }
 
@@ -510,6 +812,34 @@ pub mod rate_limit {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_server_clock_uses_fastest_sample() {
+        use time::{ClockSample, ServerClock};
+
+        let clock = ServerClock::new();
+        assert!(!clock.is_synced());
+        assert_eq!(clock.offset_millis(), 0);
+
+        let slow = ClockSample {
+            sent_millis: 1_000_000,
+            received_millis: 1_004_000,
+            server_secs: 1_010,
+        };
+        // Server is ~5s ahead of the local clock
+        let fast = ClockSample {
+            sent_millis: 1_000_000,
+            received_millis: 1_000_100,
+            server_secs: 1_005,
+        };
+        clock.record(&[slow, fast]);
+
+        assert!(clock.is_synced());
+        assert_eq!(clock.round_trip(), Duration::from_millis(100));
+        assert_eq!(clock.offset_millis(), 1_005_500 - 1_000_050);
+        let skewed = clock.now_secs() as i64 - time::now_secs() as i64;
+        assert!((4..=6).contains(&skewed));
+    }
+
     #[test]
     fn test_round_to_tick() {
         use math::round_to_tick;
@@ -552,4 +882,73 @@ mod tests {
         let invalid = "invalid_address";
         assert!(parse_address(invalid).is_err());
     }
+
+    #[test]
+    fn test_token_bucket_try_consume() {
+        use rate_limit::TokenBucket;
+
+        let bucket = TokenBucket::new(2, 1);
+        assert!(bucket.try_consume());
+        assert!(bucket.try_consume());
+        assert!(!bucket.try_consume());
+        assert_eq!(bucket.stats().requests, 2);
+    }
+
+    #[test]
+    fn test_rate_limit_validation() {
+        use rate_limit::{EndpointFamily, RateLimit, RateLimitConfig};
+
+        assert!(RateLimit::new(1, 1).validate().is_ok());
+        assert!(RateLimit::new(1, 1_000_000_000).validate().is_ok());
+        for limit in [
+            RateLimit::new(0, 10),
+            RateLimit::new(10, 0),
+            RateLimit::new(10, 1_000_000_001),
+        ] {
+            assert!(matches!(limit.validate(), Err(PolyError::Config { .. })));
+            let config = RateLimitConfig::new().with_limit(EndpointFamily::Cancels, limit);
+            assert!(matches!(config.validate(), Err(PolyError::Config { .. })));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_waits_and_records_delay() {
+        use rate_limit::{EndpointFamily, RateLimit, RateLimitConfig, RateLimiter};
+
+        let config =
+            RateLimitConfig::new().with_limit(EndpointFamily::OrderPosting, RateLimit::new(1, 20));
+        let limiter = RateLimiter::new(&config);
+
+        assert_eq!(
+            limiter.acquire(EndpointFamily::OrderPosting).await,
+            Duration::ZERO
+        );
+        let delay = limiter.acquire(EndpointFamily::OrderPosting).await;
+        assert!(delay > Duration::ZERO);
+
+        let stats = limiter.stats(EndpointFamily::OrderPosting).unwrap();
+        assert_eq!(stats.requests, 2);
+        assert_eq!(stats.delayed_requests, 1);
+        assert_eq!(stats.max_delay, delay);
+
+        // Families without a configured limit pass straight through
+        assert_eq!(limiter.acquire(EndpointFamily::Gamma).await, Duration::ZERO);
+        assert!(limiter.stats(EndpointFamily::Gamma).is_none());
+    }
+
+    #[test]
+    fn test_retry_backoff_delay() {
+        use retry::RetryConfig;
+
+        let config = RetryConfig {
+            max_attempts: 5,
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(300),
+            backoff_factor: 2.0,
+            jitter: false,
+        };
+        assert_eq!(config.backoff_delay(0), Duration::from_millis(100));
+        assert_eq!(config.backoff_delay(1), Duration::from_millis(200));
+        assert_eq!(config.backoff_delay(2), Duration::from_millis(300));
+    }
 }
@@ -0,0 +1,111 @@
+//! Polymarket wallet address derivation
+//!
+//! Proxy wallets and Gnosis Safes are deployed with CREATE2, so their
+//! addresses follow from the owning EOA and the factory parameters in
+//! [`NetworkConfig`] without any RPC call.
+
+use crate::config::{NetworkConfig, WalletFactory};
+use crate::errors::{PolyError, Result};
+use crate::orders::SigType;
+use alloy_primitives::{Address, keccak256};
+use alloy_sol_types::SolValue;
+
+/// Address of the proxy wallet owned by `owner`.
+///
+/// The proxy factory salts with the packed 20-byte owner address.
+pub fn derive_proxy_wallet(owner: Address, factory: &WalletFactory) -> Address {
+    let salt = keccak256(owner.abi_encode_packed());
+    factory.address.create2(salt, factory.init_code_hash)
+}
+
+/// Address of the Gnosis Safe owned by `owner`.
+///
+/// The Safe factory salts with the ABI-encoded (32-byte padded) owner address.
+pub fn derive_safe_wallet(owner: Address, factory: &WalletFactory) -> Address {
+    let salt = keccak256(owner.abi_encode());
+    factory.address.create2(salt, factory.init_code_hash)
+}
+
+/// Funder address for orders signed by `owner` with `sig_type`.
+///
+/// EOA orders are funded by the owner itself; proxy and Safe orders by the
+/// wallet derived from the network's factory.
+pub fn derive_funder(
+    owner: Address,
+    sig_type: SigType,
+    network: &NetworkConfig,
+) -> Result<Address> {
+    let (factory, kind) = match sig_type {
+        SigType::Eoa => return Ok(owner),
+        SigType::PolyProxy => (network.proxy_factory, "proxy wallet"),
+        SigType::PolyGnosisSafe => (network.safe_factory, "Gnosis Safe"),
+    };
+    let factory = factory.ok_or_else(|| {
+        PolyError::config(format!(
+            "No {} factory configured for {}",
+            kind, network.name
+        ))
+    })?;
+
+    Ok(match sig_type {
+        SigType::PolyProxy => derive_proxy_wallet(owner, &factory),
+        _ => derive_safe_wallet(owner, &factory),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{POLYGON_PROXY_FACTORY, POLYGON_SAFE_FACTORY};
+    use alloy_primitives::{B256, address};
+
+    #[test]
+    fn test_wallet_salts() {
+        let owner = Address::repeat_byte(0x11);
+
+        let mut padded = B256::ZERO;
+        padded[12..].copy_from_slice(owner.as_slice());
+        let safe = POLYGON_SAFE_FACTORY
+            .address
+            .create2(keccak256(padded), POLYGON_SAFE_FACTORY.init_code_hash);
+        assert_eq!(derive_safe_wallet(owner, &POLYGON_SAFE_FACTORY), safe);
+
+        let proxy = POLYGON_PROXY_FACTORY
+            .address
+            .create2(keccak256(owner), POLYGON_PROXY_FACTORY.init_code_hash);
+        assert_eq!(derive_proxy_wallet(owner, &POLYGON_PROXY_FACTORY), proxy);
+        assert_ne!(safe, proxy);
+    }
+
+    #[test]
+    fn test_known_wallet_addresses() {
+        // Fixed answers for the Polygon factories; any change to a factory
+        // address, init code hash or salt encoding breaks these
+        let owner = address!("0xd1615A7B6146cDbA40a559eC876A3bcca4050890");
+        assert_eq!(
+            derive_proxy_wallet(owner, &POLYGON_PROXY_FACTORY),
+            address!("0x4c561A384A1c7144Bc0EC20F65D517945bfc8407")
+        );
+        assert_eq!(
+            derive_safe_wallet(owner, &POLYGON_SAFE_FACTORY),
+            address!("0xcD96b40916A4428D3A7bf443397D2f866cE0EA6E")
+        );
+    }
+
+    #[test]
+    fn test_derive_funder() {
+        let owner = Address::repeat_byte(0x11);
+        let mainnet = NetworkConfig::polygon_mainnet();
+        let testnet = NetworkConfig::polygon_mumbai();
+
+        assert_eq!(derive_funder(owner, SigType::Eoa, &mainnet).unwrap(), owner);
+        assert_eq!(
+            derive_funder(owner, SigType::PolyGnosisSafe, &testnet).unwrap(),
+            derive_funder(owner, SigType::PolyGnosisSafe, &mainnet).unwrap()
+        );
+        assert!(matches!(
+            derive_funder(owner, SigType::PolyProxy, &testnet),
+            Err(PolyError::Config { .. })
+        ));
+    }
+}
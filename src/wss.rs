@@ -6,13 +6,16 @@
 //! and exposes typed events for books, price changes, tick size changes, and
 //! last trade notifications.
 
+use crate::cache::MarketMetadataCache;
 use crate::errors::{PolyError, Result};
+use crate::heartbeat::SwitchHandle;
 use crate::types::{ApiCredentials, OrderSummary, Side};
 use chrono::{DateTime, Utc};
 use futures::{SinkExt, StreamExt};
 use serde::Deserialize;
 use serde_json::{Value, json};
 use std::collections::VecDeque;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::net::TcpStream;
 use tokio::time::{sleep, timeout};
@@ -200,6 +203,7 @@ pub struct WssMarketClient {
     stats: WssStats,
     disconnect_history: VecDeque<DateTime<Utc>>,
     pending_events: VecDeque<WssMarketEvent>,
+    metadata_cache: Option<Arc<MarketMetadataCache>>,
 }
 
 impl Default for WssMarketClient {
@@ -225,9 +229,17 @@ impl WssMarketClient {
             disconnect_history: VecDeque::with_capacity(5),
             connect_url,
             pending_events: VecDeque::new(),
+            metadata_cache: None,
         }
     }
 
+    /// Apply tick size changes from this feed to a client's metadata cache
+    /// (see [`ClobClient::metadata_cache`](crate::client::ClobClient::metadata_cache)).
+    pub fn with_metadata_cache(mut self, cache: Arc<MarketMetadataCache>) -> Self {
+        self.metadata_cache = Some(cache);
+        self
+    }
+
     /// Access connection stats for observability.
     pub fn stats(&self) -> WssStats {
         self.stats.clone()
@@ -347,6 +359,9 @@ impl WssMarketClient {
                     self.stats.messages_received += events.len() as u64;
                     self.stats.last_message_time = Some(Utc::now());
                     for evt in events {
+                        if let Some(cache) = &self.metadata_cache {
+                            cache.apply_market_event(&evt);
+                        }
                         self.pending_events.push_back(evt);
                     }
                     if let Some(evt) = self.pending_events.pop_front() {
@@ -391,6 +406,19 @@ pub struct WssUserClient {
     disconnect_history: VecDeque<DateTime<Utc>>,
     pending_events: VecDeque<WssUserEvent>,
     auth: ApiCredentials,
+    switch: Option<SwitchHandle>,
+}
+
+impl std::fmt::Debug for WssUserClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WssUserClient")
+            .field("connect_url", &self.connect_url)
+            .field("connected", &self.connection.is_some())
+            .field("subscribed_markets", &self.subscribed_markets)
+            .field("stats", &self.stats)
+            .field("auth", &self.auth)
+            .finish_non_exhaustive()
+    }
 }
 
 impl WssUserClient {
@@ -411,7 +439,23 @@ impl WssUserClient {
             connect_url,
             pending_events: VecDeque::new(),
             auth,
+            switch: None,
+        }
+    }
+
+    /// Report connection state to a [`DeadMansSwitch`](crate::heartbeat::DeadMansSwitch)
+    /// so a prolonged disconnect cancels resting orders.
+    pub fn with_switch_handle(mut self, handle: SwitchHandle) -> Self {
+        handle.set_connected(self.connection.is_some());
+        self.switch = Some(handle);
+        self
+    }
+
+    fn set_connection(&mut self, connection: Option<WebSocketStream<MaybeTlsStream<TcpStream>>>) {
+        if let Some(switch) = &self.switch {
+            switch.set_connected(connection.is_some());
         }
+        self.connection = connection;
     }
 
     /// Access connection stats for observability.
@@ -473,7 +517,7 @@ impl WssUserClient {
         loop {
             match connect_async(&self.connect_url).await {
                 Ok((socket, _)) => {
-                    self.connection = Some(socket);
+                    self.set_connection(Some(socket));
                     if attempts > 0 {
                         self.stats.reconnect_count += 1;
                     }
@@ -559,17 +603,17 @@ impl WssUserClient {
                     if self.disconnect_history.len() > 5 {
                         self.disconnect_history.pop_front();
                     }
-                    self.connection = None;
+                    self.set_connection(None);
                 }
                 Ok(Some(Ok(_))) => {}
                 Ok(Some(Err(err))) => {
                     warn!("WebSocket error: {}", err);
-                    self.connection = None;
+                    self.set_connection(None);
                     self.stats.errors += 1;
                     continue;
                 }
                 Ok(None) => {
-                    self.connection = None;
+                    self.set_connection(None);
                 }
                 Err(_) => {
                     if let Some(connection) = self.connection.as_mut() {
@@ -55,3 +55,24 @@ async fn data_api_positions_endpoint() {
         "Positions must belong to the requested user"
     );
 }
+
+#[tokio::test]
+async fn data_api_activity_endpoint() {
+    let user = match data_api_user() {
+        Some(user) => user,
+        None => return,
+    };
+
+    let client = DataApiClient::new();
+    let activity = client
+        .get_activity(&user, None)
+        .await
+        .expect("data-api /activity request failed");
+
+    assert!(
+        activity
+            .iter()
+            .all(|entry| entry.proxy_wallet.eq_ignore_ascii_case(&user)),
+        "Activity must belong to the requested user"
+    );
+}
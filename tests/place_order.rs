@@ -1,13 +1,13 @@
 use polysqueeze::client::{ClobClient, OrderArgs};
 use polysqueeze::errors::Result;
+use polysqueeze::orders::SigType;
 use polysqueeze::types::{OrderType, PostOrder, Side};
 use rust_decimal::Decimal;
 use rust_decimal::prelude::FromStr;
-use serde_json;
 use std::env;
 
 fn env_var(key: &str) -> String {
-    env::var(key).expect(&format!("{} must be set for place_order test", key))
+    env::var(key).unwrap_or_else(|_| panic!("{} must be set for place_order test", key))
 }
 
 fn should_run() -> bool {
@@ -34,7 +34,11 @@ async fn place_order_smoke() -> Result<()> {
     let creds = l1_client.create_or_derive_api_key(None).await?;
     let mut client = ClobClient::with_l2_headers(&base_url, &private_key, chain_id, creds.clone());
     let funder = env::var("POLY_FUNDER").expect("POLY_FUNDER is not set");
-    client.set_funder(&funder)?;
+    // 1 = proxy wallet (email login), 2 = Gnosis Safe (browser wallet)
+    let sig_type = env::var("POLY_SIGNATURE_TYPE")
+        .map(|value| value.parse::<SigType>())
+        .unwrap_or(Ok(SigType::PolyProxy))?;
+    client.set_funder(&funder, sig_type)?;
 
     let book = client
         .get_order_book(
@@ -67,7 +71,7 @@ async fn place_order_smoke() -> Result<()> {
     println!("order payload: {}", serde_json::to_string(&post_body)?);
 
     let response = client.post_order(signed_order, OrderType::GTC).await?;
-    println!("post_order response: {}", response);
+    println!("post_order response: {:?}", response);
 
     Ok(())
 }